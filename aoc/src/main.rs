@@ -0,0 +1,53 @@
+use std::path::Path;
+
+use aoc_common::{Args, MainError};
+use clap::{Parser, Subcommand};
+use day_1::Day1;
+use day_2::Day2;
+use day_3::Day3;
+use day_4::Day4;
+use day_5::Day5;
+use day_6::Day6;
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Advent of Code 2021 solutions")]
+struct Cli {
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Solve the puzzle of a single day
+    Run {
+        /// Day of the puzzle
+        #[arg(long, value_parser = clap::value_parser!(u8).range(1..=25))]
+        day: u8,
+        #[command(flatten)]
+        args: Args,
+    },
+}
+
+fn main() -> Result<(), MainError> {
+    let cli = Cli::parse();
+
+    match cli.command {
+        Command::Run { day, args } => {
+            let run = match day {
+                1 => aoc_common::run::<Day1>,
+                2 => aoc_common::run::<Day2>,
+                3 => aoc_common::run::<Day3>,
+                4 => aoc_common::run::<Day4>,
+                5 => aoc_common::run::<Day5>,
+                6 => aoc_common::run::<Day6>,
+                _ => return Err(format!("day {day} is not solved yet").into()),
+            };
+
+            let day_dir = Path::new(env!("CARGO_MANIFEST_DIR"))
+                .join("..")
+                .join(format!("day_{day}"));
+
+            run(&args, &day_dir)
+        }
+    }
+}
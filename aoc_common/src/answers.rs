@@ -0,0 +1,136 @@
+use std::{error::Error, fmt, fs, path::Path};
+
+use serde::Deserialize;
+use serde_json::Value;
+
+use crate::report::Report;
+
+/// The recorded answers of a day, as read from its `answers.toml`.
+#[derive(Debug, Deserialize)]
+pub struct Answers {
+    #[serde(rename = "part1")]
+    pub part_1: Option<Value>,
+    #[serde(rename = "part2")]
+    pub part_2: Option<Value>,
+}
+
+impl Answers {
+    pub fn load(path: &Path) -> Result<Self, Box<dyn Error>> {
+        let content = fs::read_to_string(path)
+            .map_err(|e| format!("failed to read {}: {e}", path.display()))?;
+
+        toml::from_str(&content)
+            .map_err(|e| format!("failed to parse {}: {e}", path.display()).into())
+    }
+
+    /// Compares every part solved in `report` to its recorded answer.
+    pub fn verify(&self, report: &Report) -> Result<(), VerifyError> {
+        let mut mismatches = Vec::new();
+
+        for (part, expected, actual) in [
+            (1, &self.part_1, &report.part_1),
+            (2, &self.part_2, &report.part_2),
+        ] {
+            if let Some(actual) = actual {
+                if expected.as_ref() != Some(actual) {
+                    mismatches.push(Mismatch {
+                        part,
+                        expected: expected.clone(),
+                        actual: actual.clone(),
+                    });
+                }
+            }
+        }
+
+        if mismatches.is_empty() {
+            Ok(())
+        } else {
+            Err(VerifyError { mismatches })
+        }
+    }
+}
+
+#[derive(Debug, PartialEq)]
+pub struct Mismatch {
+    pub part: u8,
+    pub expected: Option<Value>,
+    pub actual: Value,
+}
+
+impl fmt::Display for Mismatch {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.expected {
+            Some(expected) => write!(
+                f,
+                "part {}: expected {expected}, got {}",
+                self.part, self.actual
+            ),
+            None => write!(
+                f,
+                "part {}: no recorded answer, got {}",
+                self.part, self.actual
+            ),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct VerifyError {
+    pub mismatches: Vec<Mismatch>,
+}
+
+impl fmt::Display for VerifyError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "answers do not match")?;
+
+        for mismatch in &self.mismatches {
+            write!(f, "\n  {mismatch}")?;
+        }
+
+        Ok(())
+    }
+}
+
+impl Error for VerifyError {}
+
+#[cfg(test)]
+mod tests {
+    use serde_json::json;
+
+    use super::*;
+    use crate::report::Timings;
+
+    fn report(part_1: Option<Value>, part_2: Option<Value>) -> Report {
+        Report {
+            day: 1,
+            part_1,
+            part_2,
+            timings_us: Timings::default(),
+            error: None,
+        }
+    }
+
+    #[test]
+    fn test_verify() {
+        let answers: Answers = toml::from_str("part1 = 7\npart2 = 5\n").unwrap();
+
+        assert!(answers
+            .verify(&report(Some(json!(7)), Some(json!(5))))
+            .is_ok());
+        assert!(answers.verify(&report(None, Some(json!(5)))).is_ok());
+    }
+
+    #[test]
+    fn test_verify_mismatch() {
+        let answers: Answers = toml::from_str("part1 = 7\n").unwrap();
+
+        let error = answers
+            .verify(&report(Some(json!(8)), Some(json!(5))))
+            .unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            "answers do not match\n  part 1: expected 7, got 8\n  part 2: no recorded answer, got 5"
+        );
+    }
+}
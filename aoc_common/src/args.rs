@@ -0,0 +1,43 @@
+use std::path::Path;
+
+use clap::{Parser, ValueEnum};
+
+use crate::input::{Input, InputError};
+
+// shared by every day's binary and flattened into `aoc run`
+/// Solves the day's puzzle.
+#[derive(Debug, Parser)]
+pub struct Args {
+    /// Puzzle input file, `-` reads from stdin [default: the day's `input.txt`]
+    #[arg(long)]
+    pub input: Option<Input>,
+    /// Solve only this part, both parts are solved when omitted
+    #[arg(long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    pub part: Option<u8>,
+    /// How the answers are printed
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    pub format: Format,
+    /// Compare the answers to the day's `answers.toml` and fail on a mismatch
+    #[arg(long)]
+    pub verify: bool,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum)]
+pub enum Format {
+    Text,
+    Json,
+}
+
+impl Args {
+    pub fn from_env() -> Self {
+        Self::parse()
+    }
+
+    /// Reads the selected input, falling back to `default` when `--input` is omitted.
+    pub fn read_input(&self, default: &Path) -> Result<String, InputError> {
+        match &self.input {
+            Some(input) => input.read(),
+            None => Input::File(default.to_path_buf()).read(),
+        }
+    }
+}
@@ -0,0 +1,92 @@
+use std::{
+    convert::Infallible,
+    error::Error,
+    fmt, fs,
+    io::{self, Read},
+    path::PathBuf,
+    str::FromStr,
+};
+
+/// Where the puzzle input is read from, `-` on the command line selects stdin.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Input {
+    Stdin,
+    File(PathBuf),
+}
+
+impl Input {
+    pub fn read(&self) -> Result<String, InputError> {
+        let result = match self {
+            Input::Stdin => {
+                let mut buffer = String::new();
+                io::stdin().read_to_string(&mut buffer).map(|_| buffer)
+            }
+            Input::File(path) => fs::read_to_string(path),
+        };
+
+        result.map_err(|source| InputError {
+            input: self.clone(),
+            source,
+        })
+    }
+}
+
+impl FromStr for Input {
+    type Err = Infallible;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "-" => Ok(Input::Stdin),
+            _ => Ok(Input::File(PathBuf::from(s))),
+        }
+    }
+}
+
+impl fmt::Display for Input {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Input::Stdin => write!(f, "stdin"),
+            Input::File(path) => write!(f, "{}", path.display()),
+        }
+    }
+}
+
+#[derive(Debug)]
+pub struct InputError {
+    input: Input,
+    source: io::Error,
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "failed to read {}: {}", self.input, self.source)
+    }
+}
+
+impl Error for InputError {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(Input::from_str("-").unwrap(), Input::Stdin);
+        assert_eq!(
+            Input::from_str("day_1/input.txt").unwrap(),
+            Input::File(PathBuf::from("day_1/input.txt"))
+        );
+    }
+
+    #[test]
+    fn test_missing_file() {
+        let error = Input::from_str("missing/input.txt")
+            .unwrap()
+            .read()
+            .unwrap_err();
+
+        assert!(error
+            .to_string()
+            .starts_with("failed to read missing/input.txt: "));
+    }
+}
@@ -0,0 +1,14 @@
+mod answers;
+mod args;
+mod input;
+pub mod parse;
+mod report;
+mod runner;
+mod solution;
+
+pub use answers::{Answers, Mismatch, VerifyError};
+pub use args::{Args, Format};
+pub use input::{Input, InputError};
+pub use report::{Report, Timings};
+pub use runner::{run, MainError};
+pub use solution::Solution;
@@ -0,0 +1,489 @@
+//! Combinators for the input formats shared between days.
+//!
+//! Every combinator trims surrounding whitespace from the values it parses
+//! and rejects missing or leftover fields instead of silently dropping them.
+//! Errors point at the offending token, readers that parse one line at a time
+//! add the line number with [`AtLine::at_line`].
+
+use std::{error::Error, fmt, iter, num::ParseIntError, str::FromStr};
+
+pub type Pair<T> = (T, T);
+
+/// A parse failure, rendered like a compiler diagnostic with a caret under the offending token.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    /// 1-based line of the input
+    pub line: usize,
+    /// 1-based column of the offending token, in characters
+    pub column: usize,
+    /// The full line that failed to parse
+    pub snippet: String,
+    pub kind: ParseErrorKind,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum ParseErrorKind {
+    InvalidInteger {
+        value: String,
+        source: ParseIntError,
+    },
+    InvalidNumber {
+        value: String,
+        reason: String,
+    },
+    InvalidDigit {
+        value: char,
+    },
+    InvalidLength {
+        expected: usize,
+        actual: usize,
+    },
+    InvalidValue {
+        name: &'static str,
+        value: String,
+    },
+    MissingField {
+        name: &'static str,
+    },
+    UnexpectedField {
+        value: String,
+    },
+    /// A valid value that is not allowed where it appears
+    Unsupported {
+        value: String,
+        reason: String,
+    },
+}
+
+impl ParseError {
+    /// An error at `token`, which has to be a slice of the `line` it was parsed from.
+    pub fn new(line: &str, token: &str, kind: ParseErrorKind) -> Self {
+        let offset = (token.as_ptr() as usize).wrapping_sub(line.as_ptr() as usize);
+        let offset = if offset <= line.len() { offset } else { 0 };
+
+        Self {
+            line: 1,
+            column: line[..offset].chars().count() + 1,
+            snippet: line.trim_end_matches(['\r', '\n']).to_string(),
+            kind,
+        }
+    }
+
+    pub fn at_line(mut self, line: usize) -> Self {
+        self.line = line;
+        self
+    }
+}
+
+/// Adds the line number to the errors of parsers that only see a single line.
+pub trait AtLine<T> {
+    fn at_line(self, line: usize) -> Result<T, ParseError>;
+}
+
+impl<T> AtLine<T> for Result<T, ParseError> {
+    fn at_line(self, line: usize) -> Result<T, ParseError> {
+        self.map_err(|e| e.at_line(line))
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let indent = " ".repeat(self.column - 1);
+
+        writeln!(f, "{}", self.kind)?;
+        writeln!(f, "{gutter}--> line {}, column {}", self.line, self.column)?;
+        writeln!(f, "{gutter} |")?;
+        writeln!(f, "{number} | {}", self.snippet)?;
+        write!(f, "{gutter} | {indent}^")
+    }
+}
+
+impl Error for ParseError {}
+
+impl fmt::Display for ParseErrorKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseErrorKind::InvalidInteger { value, source } => {
+                write!(f, "invalid integer {value:?}: {source}")
+            }
+            ParseErrorKind::InvalidNumber { value, reason } => {
+                write!(f, "invalid number {value:?}: {reason}")
+            }
+            ParseErrorKind::InvalidDigit { value } => write!(f, "invalid digit {value:?}"),
+            ParseErrorKind::InvalidLength { expected, actual } => {
+                write!(f, "expected {expected} values, found {actual}")
+            }
+            ParseErrorKind::InvalidValue { name, value } => write!(f, "invalid {name} {value:?}"),
+            ParseErrorKind::MissingField { name } => write!(f, "{name} not found"),
+            ParseErrorKind::UnexpectedField { value } => write!(f, "unexpected {value:?}"),
+            ParseErrorKind::Unsupported { value, reason } => {
+                write!(f, "unsupported {value:?}: {reason}")
+            }
+        }
+    }
+}
+
+/// A single integer, such as `199` or ` 42 `.
+pub fn integer<T>(input: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    integer_in(input, input)
+}
+
+/// Like [`integer`], for a token sliced out of `line`, so errors point into the whole line.
+pub fn integer_in<T>(line: &str, input: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let value = input.trim();
+
+    value.parse().map_err(|source| {
+        let kind = ParseErrorKind::InvalidInteger {
+            value: value.to_string(),
+            source,
+        };
+
+        ParseError::new(line, value, kind)
+    })
+}
+
+/// A single number of any type that implements `FromStr`, such as `-3` or `199.5`.
+pub fn number<T>(input: &str) -> Result<T, ParseError>
+where
+    T: FromStr<Err: fmt::Display>,
+{
+    let value = input.trim();
+
+    value.parse().map_err(|e: T::Err| {
+        let kind = ParseErrorKind::InvalidNumber {
+            value: value.to_string(),
+            reason: e.to_string(),
+        };
+
+        ParseError::new(input, value, kind)
+    })
+}
+
+/// The next field of a split of `line`, named `name` in the error when it is missing.
+pub fn field<'a>(
+    line: &'a str,
+    fields: &mut impl Iterator<Item = &'a str>,
+    name: &'static str,
+) -> Result<&'a str, ParseError> {
+    field_in(line, line, fields, name)
+}
+
+fn field_in<'a>(
+    line: &str,
+    input: &'a str,
+    fields: &mut impl Iterator<Item = &'a str>,
+    name: &'static str,
+) -> Result<&'a str, ParseError> {
+    fields.next().ok_or_else(|| {
+        let end = &input[input.trim_end().len()..];
+
+        ParseError::new(line, end, ParseErrorKind::MissingField { name })
+    })
+}
+
+/// Fails when a split of `line` still has fields left over.
+pub fn finish<'a>(
+    line: &'a str,
+    fields: &mut impl Iterator<Item = &'a str>,
+) -> Result<(), ParseError> {
+    match fields.next() {
+        Some(value) => {
+            let kind = ParseErrorKind::UnexpectedField {
+                value: value.to_string(),
+            };
+
+            Err(ParseError::new(line, value, kind))
+        }
+        None => Ok(()),
+    }
+}
+
+/// Integers separated by commas, such as `7,4,9,5,11`.
+pub fn comma_separated<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    input
+        .trim()
+        .split(',')
+        .map(|value| integer_in(input, value))
+        .collect()
+}
+
+/// Integers separated by any amount of whitespace, such as `22 13 17 11  0`.
+pub fn whitespace_separated<T>(input: &str) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    input
+        .split_whitespace()
+        .map(|value| integer_in(input, value))
+        .collect()
+}
+
+/// One row of exactly `width` whitespace separated integers per line, skipping blank lines.
+pub fn whitespace_grid<T>(input: &str, width: usize) -> Result<Vec<Vec<T>>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    input
+        .lines()
+        .enumerate()
+        .filter(|(_, line)| !line.trim().is_empty())
+        .map(|(index, line)| whitespace_row(line, width).at_line(index + 1))
+        .collect()
+}
+
+fn whitespace_row<T>(line: &str, width: usize) -> Result<Vec<T>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let row = whitespace_separated(line)?;
+
+    if row.len() != width {
+        let kind = ParseErrorKind::InvalidLength {
+            expected: width,
+            actual: row.len(),
+        };
+
+        return Err(ParseError::new(line, line.trim(), kind));
+    }
+
+    Ok(row)
+}
+
+/// Groups of lines separated by one or more blank lines, each with the 1-based line it starts on.
+///
+/// Lines may end in `\n` or `\r\n`, the separating blank lines are not part of any block.
+pub fn blocks(input: &str) -> impl Iterator<Item = (usize, &str)> {
+    let mut lines = input.lines().enumerate().peekable();
+
+    iter::from_fn(move || {
+        let (index, first) = lines.find(|(_, line)| !line.trim().is_empty())?;
+
+        let mut last = first;
+        while let Some((_, line)) = lines.next_if(|(_, line)| !line.trim().is_empty()) {
+            last = line;
+        }
+
+        // both lines are slices of `input`, so the block is everything in between
+        let start = first.as_ptr() as usize - input.as_ptr() as usize;
+        let end = last.as_ptr() as usize - input.as_ptr() as usize + last.len();
+
+        Some((index + 1, &input[start..end]))
+    })
+}
+
+/// A row of exactly `N` binary digits, such as `00100`.
+pub fn binary_digits<const N: usize>(input: &str) -> Result<[u32; N], ParseError> {
+    let value = input.trim();
+
+    let digits = value
+        .char_indices()
+        .map(|(index, ch)| match ch {
+            '0' => Ok(0),
+            '1' => Ok(1),
+            _ => {
+                let kind = ParseErrorKind::InvalidDigit { value: ch };
+                Err(ParseError::new(input, &value[index..], kind))
+            }
+        })
+        .collect::<Result<Vec<u32>, _>>()?;
+
+    let actual = digits.len();
+
+    digits.try_into().map_err(|_| {
+        let kind = ParseErrorKind::InvalidLength {
+            expected: N,
+            actual,
+        };
+
+        ParseError::new(input, value, kind)
+    })
+}
+
+/// Two integers separated by `separator`, such as `0,9`.
+pub fn pair<T>(input: &str, separator: char) -> Result<Pair<T>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    pair_in(input, input, separator)
+}
+
+/// Like [`pair`], for a token sliced out of `line`, so errors point into the whole line.
+pub fn pair_in<T>(line: &str, input: &str, separator: char) -> Result<Pair<T>, ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let mut fields = input.split(separator);
+
+    let a = integer_in(line, field_in(line, input, &mut fields, "first value")?)?;
+    let b = integer_in(line, field_in(line, input, &mut fields, "second value")?)?;
+    finish(line, &mut fields)?;
+
+    Ok((a, b))
+}
+
+/// A segment between two points, such as `0,9 -> 5,9`.
+pub fn segment<T>(input: &str) -> Result<(Pair<T>, Pair<T>), ParseError>
+where
+    T: FromStr<Err = ParseIntError>,
+{
+    let mut fields = input.split("->");
+
+    let start = pair_in(input, field(input, &mut fields, "segment start")?, ',')?;
+    let end = pair_in(input, field(input, &mut fields, "segment end")?, ',')?;
+    finish(input, &mut fields)?;
+
+    Ok((start, end))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_integer() {
+        assert_eq!(integer::<u64>(" 199 "), Ok(199));
+        assert!(matches!(
+            integer::<u64>("-3").map_err(|e| e.kind),
+            Err(ParseErrorKind::InvalidInteger { .. })
+        ));
+    }
+
+    #[test]
+    fn test_number() {
+        assert_eq!(number::<i64>("-3"), Ok(-3));
+        assert_eq!(number::<f64>(" 199.5 "), Ok(199.5));
+        assert_eq!(
+            number::<f64>("deep").map_err(|e| e.kind),
+            Err(ParseErrorKind::InvalidNumber {
+                value: "deep".to_string(),
+                reason: "invalid float literal".to_string()
+            })
+        );
+    }
+
+    #[test]
+    fn test_comma_separated() {
+        assert_eq!(
+            comma_separated::<i64>("3,4,3,1,2\n"),
+            Ok(vec![3, 4, 3, 1, 2])
+        );
+
+        let error = comma_separated::<i64>("3,,1").unwrap_err();
+        assert_eq!((error.line, error.column), (1, 3));
+    }
+
+    #[test]
+    fn test_whitespace_grid() {
+        assert_eq!(
+            whitespace_grid::<usize>("22 13  0\n\n 8  2 23\n", 3),
+            Ok(vec![vec![22, 13, 0], vec![8, 2, 23]])
+        );
+
+        let error = whitespace_grid::<usize>("22 13  0\n 8  x 23\n", 3).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 5));
+        assert_eq!(error.snippet, " 8  x 23");
+
+        let error = whitespace_grid::<usize>("22 13  0\n 8  2\n", 3).unwrap_err();
+        assert_eq!((error.line, error.column), (2, 2));
+        assert_eq!(
+            error.kind,
+            ParseErrorKind::InvalidLength {
+                expected: 3,
+                actual: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_blocks() {
+        let lf = "7,4,9\n\n22 13\n8 2\n\n\n3 15\n9 18\n";
+        assert_eq!(
+            blocks(lf).collect::<Vec<_>>(),
+            [(1, "7,4,9"), (3, "22 13\n8 2"), (7, "3 15\n9 18")]
+        );
+
+        let crlf = "7,4,9\r\n\r\n22 13\r\n8 2\r\n  \r\n3 15\r\n";
+        assert_eq!(
+            blocks(crlf).collect::<Vec<_>>(),
+            [(1, "7,4,9"), (3, "22 13\r\n8 2"), (6, "3 15")]
+        );
+
+        assert_eq!(blocks("\n\n").count(), 0);
+    }
+
+    #[test]
+    fn test_binary_digits() {
+        assert_eq!(binary_digits::<5>("00100"), Ok([0, 0, 1, 0, 0]));
+        assert_eq!(
+            binary_digits::<5>("0010").map_err(|e| e.kind),
+            Err(ParseErrorKind::InvalidLength {
+                expected: 5,
+                actual: 4
+            })
+        );
+
+        let error = binary_digits::<5>("00200").unwrap_err();
+        assert_eq!(error.kind, ParseErrorKind::InvalidDigit { value: '2' });
+        assert_eq!(error.column, 3);
+    }
+
+    #[test]
+    fn test_segment() {
+        assert_eq!(segment::<usize>("0,9 -> 5,9"), Ok(((0, 9), (5, 9))));
+        assert!(matches!(
+            segment::<usize>("0,9 -> ").map_err(|e| e.kind),
+            Err(ParseErrorKind::InvalidInteger { .. })
+        ));
+        assert_eq!(
+            segment::<usize>("0,9").map_err(|e| (e.column, e.kind)),
+            Err((
+                4,
+                ParseErrorKind::MissingField {
+                    name: "segment end"
+                }
+            ))
+        );
+        assert_eq!(
+            segment::<usize>("0,9,1 -> 5,9").map_err(|e| (e.column, e.kind)),
+            Err((
+                5,
+                ParseErrorKind::UnexpectedField {
+                    value: "1 ".to_string()
+                }
+            ))
+        );
+        assert_eq!(
+            segment::<usize>("0,9 -> 5,x").map_err(|e| e.column),
+            Err(10)
+        );
+        assert_eq!(
+            segment::<usize>("0,9 -> 5 ").map_err(|e| (e.snippet, e.column)),
+            Err(("0,9 -> 5 ".to_string(), 9))
+        );
+    }
+
+    #[test]
+    fn test_display() {
+        let error = segment::<usize>("0,9 -> 5,x").at_line(12).unwrap_err();
+
+        assert_eq!(
+            error.to_string(),
+            r#"invalid integer "x": invalid digit found in string
+  --> line 12, column 10
+   |
+12 | 0,9 -> 5,x
+   |          ^"#
+        );
+    }
+}
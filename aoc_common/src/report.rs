@@ -0,0 +1,140 @@
+use std::{error::Error, fmt, time::Instant};
+
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::solution::Solution;
+
+/// The answers of a single day, along with how long each part took to solve.
+#[derive(Debug, Serialize)]
+pub struct Report {
+    pub day: u8,
+    #[serde(rename = "part1", skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<Value>,
+    #[serde(rename = "part2", skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<Value>,
+    pub timings_us: Timings,
+    /// The first part that failed, the other parts are still solved and reported
+    #[serde(skip)]
+    pub error: Option<Box<dyn Error>>,
+}
+
+#[derive(Debug, Default, Serialize)]
+pub struct Timings {
+    #[serde(rename = "part1", skip_serializing_if = "Option::is_none")]
+    pub part_1: Option<u128>,
+    #[serde(rename = "part2", skip_serializing_if = "Option::is_none")]
+    pub part_2: Option<u128>,
+}
+
+impl Report {
+    /// Solves the given part, or both parts when `part` is `None`.
+    ///
+    /// A part that fails does not hide the answer of the other one, its error is kept in `error`.
+    pub fn solve<S: Solution>(input: &str, part: Option<u8>) -> Self {
+        let mut report = Self {
+            day: S::DAY,
+            part_1: None,
+            part_2: None,
+            timings_us: Timings::default(),
+            error: None,
+        };
+
+        if part.is_none() || part == Some(1) {
+            let start = Instant::now();
+
+            match S::part_1(input).and_then(|answer| Ok(serde_json::to_value(answer)?)) {
+                Ok(answer) => {
+                    report.timings_us.part_1 = Some(start.elapsed().as_micros());
+                    report.part_1 = Some(answer);
+                }
+                Err(error) => {
+                    report.error.get_or_insert(error);
+                }
+            }
+        }
+
+        if part.is_none() || part == Some(2) {
+            let start = Instant::now();
+
+            match S::part_2(input).and_then(|answer| Ok(serde_json::to_value(answer)?)) {
+                Ok(answer) => {
+                    report.timings_us.part_2 = Some(start.elapsed().as_micros());
+                    report.part_2 = Some(answer);
+                }
+                Err(error) => {
+                    report.error.get_or_insert(error);
+                }
+            }
+        }
+
+        report
+    }
+}
+
+impl fmt::Display for Report {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if let Some(answer) = &self.part_1 {
+            writeln!(f, "part 1: {answer}")?;
+        }
+
+        if let Some(answer) = &self.part_2 {
+            writeln!(f, "part 2: {answer}")?;
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    struct Example;
+
+    impl Solution for Example {
+        const DAY: u8 = 1;
+
+        type Part1 = usize;
+        type Part2 = u64;
+
+        fn part_1(input: &str) -> Result<Self::Part1, Box<dyn Error>> {
+            Ok(input.len())
+        }
+
+        fn part_2(input: &str) -> Result<Self::Part2, Box<dyn Error>> {
+            Ok(input.parse()?)
+        }
+    }
+
+    #[test]
+    fn test_display() {
+        let report = Report::solve::<Example>("42", None);
+
+        assert_eq!(report.to_string(), "part 1: 2\npart 2: 42\n");
+    }
+
+    #[test]
+    fn test_json() {
+        let report = Report::solve::<Example>("42", Some(2));
+        let json = serde_json::to_value(&report).unwrap();
+
+        assert_eq!(json["day"], 1);
+        assert_eq!(json["part2"], 42);
+        assert!(json.get("part1").is_none());
+        assert!(json["timings_us"]["part2"].is_u64());
+        assert!(json["timings_us"].get("part1").is_none());
+    }
+
+    #[test]
+    fn test_failed_part() {
+        let report = Report::solve::<Example>("x", None);
+
+        assert_eq!(report.to_string(), "part 1: 1\n");
+        assert_eq!(
+            report.error.map(|error| error.to_string()),
+            Some("invalid digit found in string".to_string())
+        );
+        assert!(report.timings_us.part_2.is_none());
+    }
+}
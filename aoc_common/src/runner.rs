@@ -0,0 +1,60 @@
+use std::{error::Error, fmt, path::Path};
+
+use crate::{
+    answers::Answers,
+    args::{Args, Format},
+    report::Report,
+    solution::Solution,
+};
+
+/// Solves the puzzle as selected by `args` and prints the answers.
+///
+/// `day_dir` holds the day's default `input.txt` and its `answers.toml`.
+pub fn run<S: Solution>(args: &Args, day_dir: &Path) -> Result<(), MainError> {
+    let input = args.read_input(&day_dir.join("input.txt"))?;
+    let mut report = Report::solve::<S>(&input, args.part);
+
+    // the answers of the parts that were solved are printed before the error of another part
+    match args.format {
+        Format::Text => print!("{report}"),
+        Format::Json => println!("{}", serde_json::to_string(&report)?),
+    }
+
+    if let Some(error) = report.error.take() {
+        return Err(error.into());
+    }
+
+    if args.verify {
+        Answers::load(&day_dir.join("answers.toml"))?.verify(&report)?;
+    }
+
+    Ok(())
+}
+
+/// An error returned from `main`.
+///
+/// `main` reports errors through `Debug`, this prints the `Display` message
+/// of the error and of its sources instead of their derived `Debug`.
+/// Errors that already include their cause in their message, such as
+/// `InputError`, leave `Error::source` empty so the cause is not printed twice.
+pub struct MainError(Box<dyn Error>);
+
+impl<E: Into<Box<dyn Error>>> From<E> for MainError {
+    fn from(error: E) -> Self {
+        Self(error.into())
+    }
+}
+
+impl fmt::Debug for MainError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.0)?;
+
+        let mut source = self.0.source();
+        while let Some(error) = source {
+            write!(f, "\n\nCaused by:\n    {error}")?;
+            source = error.source();
+        }
+
+        Ok(())
+    }
+}
@@ -0,0 +1,18 @@
+use std::{error::Error, fmt::Display};
+
+use serde::Serialize;
+
+/// A puzzle solver for a single day, dispatched to by the `aoc` runner.
+///
+/// Both parts receive the whole puzzle input and parse it themselves,
+/// so either part can be run on its own.
+pub trait Solution {
+    const DAY: u8;
+
+    type Part1: Display + Serialize;
+    type Part2: Display + Serialize;
+
+    fn part_1(input: &str) -> Result<Self::Part1, Box<dyn Error>>;
+
+    fn part_2(input: &str) -> Result<Self::Part2, Box<dyn Error>>;
+}
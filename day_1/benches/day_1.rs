@@ -0,0 +1,52 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_1::{parallel, parser_sliding_window::ParserSlidingWindow};
+
+const EXAMPLE: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+const INPUT: &str = include_str!("../input.txt");
+
+fn increases<const N: usize>(input: &str) -> u64 {
+    let mut parser = ParserSlidingWindow::<N, u64>::new();
+
+    for line in input.lines() {
+        parser.parse(line).unwrap();
+    }
+
+    parser.report().increases
+}
+
+fn sliding_window_parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("ParserSlidingWindow::parse");
+
+    for (name, input) in [("example", EXAMPLE), ("input", INPUT)] {
+        group.bench_function(name, |b| b.iter(|| increases::<3>(black_box(input))));
+    }
+
+    // a wide window should cost the same per sample as the default one
+    group.bench_function("input/60", |b| b.iter(|| increases::<60>(black_box(INPUT))));
+
+    group.finish();
+}
+
+fn parallel_count_increases(c: &mut Criterion) {
+    let mut group = c.benchmark_group("parallel::count_increases");
+
+    // large enough to be split into several chunks
+    let input = INPUT.repeat(200);
+
+    group.bench_function("input", |b| {
+        b.iter(|| parallel::count_increases::<3>(black_box(INPUT.as_bytes())))
+    });
+    group.bench_function("input*200", |b| {
+        b.iter(|| parallel::count_increases::<3>(black_box(input.as_bytes())))
+    });
+    group.bench_function("input*200/sequential", |b| {
+        b.iter(|| increases::<3>(black_box(&input)))
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, sliding_window_parse, parallel_count_increases);
+criterion_main!(benches);
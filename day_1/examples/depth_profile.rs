@@ -0,0 +1,29 @@
+use std::{error::Error, fs, path::PathBuf};
+
+use aoc_common::parse::{self, AtLine};
+use day_1::render::DepthProfile;
+
+// Plots a sonar sweep: depth_profile [input] [output.svg]
+fn main() -> Result<(), Box<dyn Error>> {
+    let mut args = std::env::args().skip(1);
+
+    let input = args
+        .next()
+        .map(PathBuf::from)
+        .unwrap_or_else(|| PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("input.txt"));
+    let output = args
+        .next()
+        .unwrap_or_else(|| "depth_profile.svg".to_string());
+
+    let text = fs::read_to_string(&input)?;
+    let depths = text
+        .lines()
+        .enumerate()
+        .map(|(index, line)| parse::number::<f64>(line).at_line(index + 1))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    DepthProfile::new(depths, 3).save(&output)?;
+    println!("wrote {output}");
+
+    Ok(())
+}
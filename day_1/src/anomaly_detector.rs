@@ -0,0 +1,133 @@
+use std::collections::VecDeque;
+
+use aoc_common::parse::{self, ParseError};
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Anomaly {
+    /// 0-based index of the sample in the sweep
+    pub index: u64,
+    pub depth: u64,
+    /// How many standard deviations the depth is away from the rolling mean
+    pub z_score: f64,
+}
+
+/// Flags depths that are more than `threshold` standard deviations away
+/// from the mean of the `N` samples before them.
+///
+/// Detection starts once `N` samples have been seen. Flagged samples still
+/// enter the window, so a lasting change of depth is only reported once.
+pub struct AnomalyDetector<const N: usize> {
+    threshold: f64,
+    index: u64,
+    window: VecDeque<u64>,
+    anomalies: Vec<Anomaly>,
+}
+
+impl<const N: usize> AnomalyDetector<N> {
+    pub fn new(threshold: f64) -> Self {
+        const { assert!(N > 1, "the window needs at least two samples") };
+
+        Self {
+            threshold,
+            index: 0,
+            window: VecDeque::with_capacity(N + 1),
+            anomalies: Vec::new(),
+        }
+    }
+
+    /// Returns the anomaly when the parsed depth is one.
+    pub fn parse(&mut self, line: &str) -> Result<Option<Anomaly>, ParseError> {
+        let depth = parse::integer::<u64>(line)?;
+
+        let anomaly = self.z_score(depth).and_then(|z_score| {
+            (z_score.abs() > self.threshold).then_some(Anomaly {
+                index: self.index,
+                depth,
+                z_score,
+            })
+        });
+
+        if let Some(anomaly) = anomaly {
+            self.anomalies.push(anomaly);
+        }
+
+        self.window.push_back(depth);
+        if self.window.len() > N {
+            self.window.pop_front();
+        }
+
+        self.index += 1;
+
+        Ok(anomaly)
+    }
+
+    /// Every anomaly found so far, in sweep order.
+    pub fn anomalies(&self) -> &[Anomaly] {
+        &self.anomalies
+    }
+
+    // the window is small enough to recompute, which avoids the drift
+    // of incrementally updated floating point sums
+    fn z_score(&self, depth: u64) -> Option<f64> {
+        if self.window.len() < N {
+            return None;
+        }
+
+        let count = N as f64;
+        let mean = self.window.iter().map(|&value| value as f64).sum::<f64>() / count;
+        let variance = self
+            .window
+            .iter()
+            .map(|&value| (value as f64 - mean).powi(2))
+            .sum::<f64>()
+            / count;
+
+        let deviation = depth as f64 - mean;
+
+        if variance == 0.0 {
+            // a flat window makes any change infinitely unlikely
+            return Some(if deviation == 0.0 {
+                0.0
+            } else {
+                f64::INFINITY.copysign(deviation)
+            });
+        }
+
+        Some(deviation / variance.sqrt())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_spike() {
+        let mut detector = AnomalyDetector::<5>::new(3.0);
+
+        let anomalies = [
+            "100", "101", "99", "100", "101", "99", "100", "500", "100", "101",
+        ]
+        .into_iter()
+        .filter_map(|line| detector.parse(line).unwrap())
+        .collect::<Vec<_>>();
+
+        assert_eq!(anomalies.len(), 1);
+        assert_eq!(anomalies[0].index, 7);
+        assert_eq!(anomalies[0].depth, 500);
+        assert!((anomalies[0].z_score - 534.8).abs() < 0.1);
+        assert_eq!(detector.anomalies(), anomalies.as_slice());
+    }
+
+    #[test]
+    fn test_flat_window() {
+        let mut detector = AnomalyDetector::<3>::new(3.0);
+
+        for line in ["5", "5", "5", "5"] {
+            assert_eq!(detector.parse(line).unwrap(), None);
+        }
+
+        let anomaly = detector.parse("4").unwrap().unwrap();
+        assert_eq!(anomaly.z_score, f64::NEG_INFINITY);
+    }
+}
@@ -0,0 +1,70 @@
+use core::{
+    fmt,
+    ops::{Add, Sub},
+    str::FromStr,
+};
+
+/// A numeric type sonar depths can be read as.
+///
+/// Depths are only compared through `PartialOrd`, so every type keeps its own rules,
+/// e.g. a `NaN` float is neither an increase nor a decrease. Implement this for a
+/// fixed-point type to read depths such as `199.5` without floating point rounding.
+pub trait Depth:
+    Copy + PartialOrd + Add<Output = Self> + Sub<Output = Self> + FromStr<Err: fmt::Display>
+{
+    const ZERO: Self;
+
+    /// Whether adding and subtracting depths is exact. Window sums of inexact types,
+    /// such as floats, keep track of their rounding error as samples enter and leave.
+    const EXACT: bool = false;
+
+    /// Used for the mean, which is a float for every type.
+    fn to_f64(self) -> f64;
+
+    /// How far apart two ordered depths are, for the largest jump of a sweep.
+    /// Subtracts by default, integers saturate where the difference would overflow.
+    fn distance(self, other: Self) -> Self {
+        if self > other {
+            self - other
+        } else {
+            other - self
+        }
+    }
+}
+
+macro_rules! impl_depth {
+    ($($ty:ty),* => $items:tt) => {
+        $(impl_depth!(@impl $ty, $items);)*
+    };
+    (@impl $ty:ty, { $($items:tt)* }) => {
+        impl Depth for $ty {
+            $($items)*
+
+            fn to_f64(self) -> f64 {
+                self as f64
+            }
+        }
+    };
+}
+
+impl_depth!(u32, u64 => {
+    const ZERO: Self = 0;
+    const EXACT: bool = true;
+
+    fn distance(self, other: Self) -> Self {
+        self.abs_diff(other)
+    }
+});
+
+impl_depth!(i32, i64 => {
+    const ZERO: Self = 0;
+    const EXACT: bool = true;
+
+    fn distance(self, other: Self) -> Self {
+        Self::try_from(self.abs_diff(other)).unwrap_or(Self::MAX)
+    }
+});
+
+impl_depth!(f32, f64 => {
+    const ZERO: Self = 0.0;
+});
@@ -0,0 +1,159 @@
+//! Iterator adapters over already parsed depths, e.g.
+//! `depths.iter().windowed_sums(3).increases()`.
+//!
+//! Only `core` is used, so these work without the `std` feature.
+
+use core::borrow::Borrow;
+
+use crate::sweep_report::SweepReport;
+
+pub trait DepthsExt: Iterator + Sized {
+    /// Sums of every window of `size` consecutive depths.
+    ///
+    /// The iterator is cloned to remember which depth leaves the window,
+    /// so every sum is computed in constant time without allocating.
+    ///
+    /// # Panics
+    ///
+    /// Panics if `size` is 0.
+    fn windowed_sums(self, size: usize) -> WindowedSums<Self>
+    where
+        Self: Clone,
+        Self::Item: Borrow<u64>,
+    {
+        assert!(size > 0, "the window needs at least one sample");
+
+        WindowedSums {
+            lag: self.clone(),
+            lead: self,
+            size,
+            sum: None,
+        }
+    }
+
+    /// How many values are larger than the value before them.
+    fn increases(self) -> u64
+    where
+        Self::Item: PartialOrd,
+    {
+        let mut increases = 0;
+        let mut previous = None;
+
+        for value in self {
+            if let Some(previous) = previous {
+                if value > previous {
+                    increases += 1;
+                }
+            }
+
+            previous = Some(value);
+        }
+
+        increases
+    }
+
+    /// Full statistics of the depths, or of the sums when chained after [`DepthsExt::windowed_sums`].
+    fn sweep_report(self) -> SweepReport
+    where
+        Self::Item: Borrow<u64>,
+    {
+        let mut report = SweepReport::new();
+
+        for value in self {
+            report.record(*value.borrow());
+        }
+
+        report
+    }
+}
+
+impl<I: Iterator> DepthsExt for I {}
+
+pub struct WindowedSums<I> {
+    lead: I,
+    lag: I,
+    size: usize,
+    sum: Option<u64>,
+}
+
+impl<I> Iterator for WindowedSums<I>
+where
+    I: Iterator,
+    I::Item: Borrow<u64>,
+{
+    type Item = u64;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let sum = match self.sum {
+            Some(sum) => {
+                let incoming = *self.lead.next()?.borrow();
+                let outgoing = *self.lag.next()?.borrow();
+
+                // `outgoing` is part of `sum`, so subtracting it first cannot overflow
+                sum - outgoing + incoming
+            }
+            None => {
+                let mut sum = 0;
+
+                for _ in 0..self.size {
+                    sum += *self.lead.next()?.borrow();
+                }
+
+                sum
+            }
+        };
+
+        self.sum = Some(sum);
+
+        Some(sum)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTHS: [u64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[test]
+    fn test_increases() {
+        assert_eq!(DEPTHS.iter().increases(), 7);
+        assert_eq!(DEPTHS.into_iter().increases(), 7);
+    }
+
+    #[test]
+    fn test_windowed_sums() {
+        let mut sums = DEPTHS.iter().windowed_sums(3);
+
+        for expected in [607, 618, 618, 617, 647, 716, 769, 792] {
+            assert_eq!(sums.next(), Some(expected));
+        }
+
+        assert_eq!(sums.next(), None);
+        assert_eq!(DEPTHS.iter().windowed_sums(3).increases(), 5);
+    }
+
+    #[test]
+    fn test_windowed_sums_short_input() {
+        assert_eq!(DEPTHS[..2].iter().windowed_sums(3).next(), None);
+        assert_eq!(DEPTHS.iter().windowed_sums(10).increases(), 0);
+    }
+
+    #[test]
+    fn test_sweep_report() {
+        let report = DEPTHS.iter().windowed_sums(3).sweep_report();
+
+        assert_eq!(report.increases, 5);
+        assert_eq!(report.largest_jump, 69);
+    }
+
+    #[test]
+    fn test_windowed_sums_near_max() {
+        let depths = [u64::MAX - 1, 1, 1];
+
+        assert_eq!(
+            depths.iter().windowed_sums(2).collect::<Vec<_>>(),
+            [u64::MAX, 2]
+        );
+    }
+}
@@ -0,0 +1,16 @@
+use std::{io, path::PathBuf};
+
+use aoc_common::parse::ParseError;
+use thiserror::Error;
+
+#[derive(Debug, Error)]
+pub enum SweepError {
+    #[error("failed to map {}: {error}", path.display())]
+    Map { path: PathBuf, error: io::Error },
+    #[error("failed to read the sweep: {0}")]
+    Read(io::Error),
+    #[error("line {line} is not valid UTF-8")]
+    InvalidUtf8 { line: usize },
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+}
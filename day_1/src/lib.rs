@@ -0,0 +1,24 @@
+#![cfg_attr(not(feature = "std"), no_std)]
+
+#[cfg(feature = "std")]
+pub mod anomaly_detector;
+pub mod depth;
+pub mod depths;
+#[cfg(feature = "std")]
+pub mod error;
+#[cfg(feature = "std")]
+pub mod live;
+#[cfg(feature = "std")]
+pub mod parallel;
+#[cfg(feature = "std")]
+pub mod parser_simple;
+#[cfg(feature = "std")]
+pub mod parser_sliding_window;
+#[cfg(feature = "std")]
+pub mod render;
+#[cfg(feature = "std")]
+mod solution;
+pub mod sweep_report;
+
+#[cfg(feature = "std")]
+pub use solution::Day1;
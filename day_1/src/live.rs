@@ -0,0 +1,165 @@
+use aoc_common::parse::{AtLine, ParseError};
+use futures::{stream, Stream, StreamExt};
+use tokio::io::{AsyncBufRead, AsyncBufReadExt};
+
+use crate::{
+    depth::Depth, error::SweepError, parser_simple::ParserSimple,
+    parser_sliding_window::ParserSlidingWindow, sweep_report::SweepReport,
+};
+
+/// A parser that keeps its statistics up to date one sample at a time.
+pub trait Sweep<T> {
+    fn parse(&mut self, line: &str) -> Result<(), ParseError>;
+
+    fn push(&mut self, depth: T);
+
+    fn report(&self) -> &SweepReport<T>;
+}
+
+impl<T: Depth> Sweep<T> for ParserSimple<T> {
+    fn parse(&mut self, line: &str) -> Result<(), ParseError> {
+        self.parse(line)
+    }
+
+    fn push(&mut self, depth: T) {
+        self.push(depth)
+    }
+
+    fn report(&self) -> &SweepReport<T> {
+        self.report()
+    }
+}
+
+impl<const N: usize, T: Depth> Sweep<T> for ParserSlidingWindow<N, T> {
+    fn parse(&mut self, line: &str) -> Result<(), ParseError> {
+        self.parse(line)
+    }
+
+    fn push(&mut self, depth: T) {
+        self.push(depth)
+    }
+
+    fn report(&self) -> &SweepReport<T> {
+        self.report()
+    }
+}
+
+/// Yields the number of increases so far after every line read from a live feed.
+///
+/// A line that does not parse yields its error and the feed carries on,
+/// the stream only ends with the feed or after a failed read.
+pub fn read_increases<R, P, T>(reader: R, parser: P) -> impl Stream<Item = Result<u64, SweepError>>
+where
+    R: AsyncBufRead + Unpin,
+    P: Sweep<T>,
+{
+    let state = Some((reader.lines(), parser, 0));
+
+    stream::unfold(state, |state| async move {
+        let (mut lines, mut parser, index) = state?;
+
+        let line = match lines.next_line().await {
+            Ok(Some(line)) => line,
+            Ok(None) => return None,
+            Err(error) => return Some((Err(SweepError::Read(error)), None)),
+        };
+
+        let index = index + 1;
+        let increases = match parser.parse(&line).at_line(index) {
+            Ok(()) => Ok(parser.report().increases),
+            Err(error) => Err(SweepError::Parse(error)),
+        };
+
+        Some((increases, Some((lines, parser, index))))
+    })
+}
+
+/// Yields the number of increases so far after every depth of a live feed.
+pub fn increases<S, P, T>(depths: S, mut parser: P) -> impl Stream<Item = u64>
+where
+    S: Stream<Item = T>,
+    P: Sweep<T>,
+{
+    depths.map(move |depth| {
+        parser.push(depth);
+        parser.report().increases
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use tokio::io::{duplex, AsyncWriteExt, BufReader};
+
+    use super::*;
+
+    const DEPTHS: [u64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    #[tokio::test]
+    async fn test_read_increases() {
+        let (mut sonar, feed) = duplex(64);
+        let increases = read_increases(BufReader::new(feed), ParserSimple::<u64>::new());
+        let mut increases = Box::pin(increases);
+
+        // every sample is counted as soon as its line is complete
+        for (depth, expected) in DEPTHS.iter().zip([0, 1, 2, 3, 3, 4, 5, 6, 6, 7]) {
+            sonar
+                .write_all(format!("{depth}\n").as_bytes())
+                .await
+                .unwrap();
+
+            assert_eq!(increases.next().await.unwrap().unwrap(), expected);
+        }
+
+        drop(sonar);
+        assert!(increases.next().await.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_sliding_window() {
+        let (mut sonar, feed) = duplex(64);
+
+        let writer = tokio::spawn(async move {
+            for depth in DEPTHS {
+                sonar
+                    .write_all(format!("{depth}\n").as_bytes())
+                    .await
+                    .unwrap();
+            }
+        });
+
+        let parser = ParserSlidingWindow::<3, u64>::new();
+        let increases: Vec<_> = read_increases(BufReader::new(feed), parser)
+            .map(Result::unwrap)
+            .collect()
+            .await;
+
+        writer.await.unwrap();
+        assert_eq!(increases, [0, 0, 0, 1, 1, 1, 2, 3, 4, 5]);
+    }
+
+    #[tokio::test]
+    async fn test_parse_errors() {
+        let feed: &[u8] = b"1\nx\n2\n";
+
+        let increases: Vec<_> = read_increases(feed, ParserSimple::<u64>::new())
+            .collect()
+            .await;
+
+        assert_eq!(increases.len(), 3);
+        assert!(matches!(
+            increases[1],
+            Err(SweepError::Parse(ParseError { line: 2, .. }))
+        ));
+        assert_eq!(increases[2].as_ref().unwrap(), &1);
+    }
+
+    #[tokio::test]
+    async fn test_increases() {
+        let parser = ParserSlidingWindow::<3, f64>::new();
+        let depths = stream::iter([1.5, 2.0, 2.5, 3.0, 1.0]);
+
+        let increases: Vec<_> = increases(depths, parser).collect().await;
+
+        assert_eq!(increases, [0, 0, 0, 1, 1]);
+    }
+}
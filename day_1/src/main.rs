@@ -1,31 +1,11 @@
-use std::{
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::path::Path;
 
-mod parser_simple;
-mod parser_sliding_window;
-
-use parser_simple::ParserSimple;
-use parser_sliding_window::ParserSlidingWindow;
+use aoc_common::{Args, MainError};
+use day_1::Day1;
 
 // https://adventofcode.com/2021/day/1
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = File::open("day_1/input.txt")?;
-    let reader = BufReader::new(file);
-
-    let mut parser_simple = ParserSimple::new();
-    let mut parser_sliding_window = ParserSlidingWindow::new();
-
-    for line in reader.lines() {
-        let line = line?;
-        parser_simple.parse(&line)?;
-        parser_sliding_window.parse(&line)?;
-    }
-
-    println!("part 1: {}", parser_simple.get_increases());
-    println!("part 2: {}", parser_sliding_window.get_increases());
+fn main() -> Result<(), MainError> {
+    let args = Args::from_env();
 
-    Ok(())
+    aoc_common::run::<Day1>(&args, Path::new(env!("CARGO_MANIFEST_DIR")))
 }
@@ -0,0 +1,315 @@
+use std::{fs::File, path::Path};
+
+use aoc_common::parse::{self, AtLine};
+use memmap2::Mmap;
+use rayon::prelude::*;
+
+use crate::error::SweepError;
+
+/// Bytes per chunk, the chunks are extended to the end of their last line.
+const CHUNK_SIZE: usize = 1 << 20;
+
+/// Counts the increases between windows of `N` depths, like [`ParserSlidingWindow`] would,
+/// with the file memory mapped and split into chunks that are parsed in parallel.
+///
+/// `N = 1` compares single depths, like [`ParserSimple`].
+///
+/// [`ParserSimple`]: crate::parser_simple::ParserSimple
+/// [`ParserSlidingWindow`]: crate::parser_sliding_window::ParserSlidingWindow
+pub fn count_file_increases<const N: usize>(path: impl AsRef<Path>) -> Result<u64, SweepError> {
+    let path = path.as_ref();
+    let map_error = |error| SweepError::Map {
+        path: path.to_path_buf(),
+        error,
+    };
+
+    let file = File::open(path).map_err(map_error)?;
+    // SAFETY: the map is only read, and the file is expected not to change while it is counted
+    let map = unsafe { Mmap::map(&file) }.map_err(map_error)?;
+
+    count_increases::<N>(&map)
+}
+
+/// Same as [`count_file_increases`], over depths that are already in memory.
+pub fn count_increases<const N: usize>(input: &[u8]) -> Result<u64, SweepError> {
+    const { assert!(N > 0, "the window needs at least one sample") };
+
+    let chunks = split(input, CHUNK_SIZE);
+
+    let summaries: Vec<_> = chunks
+        .par_iter()
+        .map(|chunk| Summary::<N>::new(chunk))
+        .collect();
+
+    let mut total = Summary::<N>::default();
+
+    for summary in summaries {
+        // errors only know their line within the chunk
+        let summary = summary.map_err(|error| match error {
+            SweepError::InvalidUtf8 { line } => SweepError::InvalidUtf8 {
+                line: total.lines + line,
+            },
+            SweepError::Parse(error) => {
+                let line = total.lines + error.line;
+                SweepError::Parse(error.at_line(line))
+            }
+            error => error,
+        })?;
+
+        total = total.merge(summary);
+    }
+
+    Ok(total.increases)
+}
+
+/// Splits after the first newline that follows every `size` bytes.
+fn split(input: &[u8], size: usize) -> Vec<&[u8]> {
+    let mut chunks = Vec::with_capacity(input.len() / size + 1);
+    let mut rest = input;
+
+    while rest.len() > size {
+        let end = match rest[size..].iter().position(|&byte| byte == b'\n') {
+            Some(newline) => size + newline + 1,
+            None => rest.len(),
+        };
+
+        let (chunk, tail) = rest.split_at(end);
+        chunks.push(chunk);
+        rest = tail;
+    }
+
+    if !rest.is_empty() {
+        chunks.push(rest);
+    }
+
+    chunks
+}
+
+/// Increases of a run of consecutive depths, along with the depths at both ends
+/// that a window can reach into from a neighbouring run.
+#[derive(Debug, Default, PartialEq)]
+struct Summary<const N: usize> {
+    lines: usize,
+    samples: usize,
+    increases: u64,
+    /// First `N` depths
+    head: Vec<u64>,
+    /// Last `N` depths
+    tail: Vec<u64>,
+}
+
+impl<const N: usize> Summary<N> {
+    fn new(chunk: &[u8]) -> Result<Self, SweepError> {
+        let text = std::str::from_utf8(chunk).map_err(|error| {
+            let valid = &chunk[..error.valid_up_to()];
+            let line = valid.iter().filter(|&&byte| byte == b'\n').count() + 1;
+
+            SweepError::InvalidUtf8 { line }
+        })?;
+
+        let mut summary = Self::default();
+
+        for (index, line) in text.lines().enumerate() {
+            let depth = parse::number::<u64>(line).at_line(index + 1)?;
+
+            summary.push(depth);
+            summary.lines += 1;
+        }
+
+        Ok(summary)
+    }
+
+    fn push(&mut self, depth: u64) {
+        // the sums of two consecutive windows only differ by the depth that
+        // entered the later one and the depth that left the earlier one
+        if self.samples >= N && depth > self.tail[0] {
+            self.increases += 1;
+        }
+
+        if self.head.len() < N {
+            self.head.push(depth);
+        }
+
+        if self.tail.len() == N {
+            self.tail.remove(0);
+        }
+        self.tail.push(depth);
+
+        self.samples += 1;
+    }
+
+    /// Joins with the run of depths that directly follows this one.
+    fn merge(self, next: Self) -> Self {
+        let mut increases = self.increases + next.increases;
+
+        // the first depths of `next` are compared with the depths `N` samples before them,
+        // which are at the end of `self` when there are enough of them
+        for (offset, &depth) in next.head.iter().enumerate() {
+            let Some(index) = (self.samples + offset).checked_sub(N) else {
+                continue;
+            };
+
+            if index < self.samples {
+                let earlier = self.tail[index + self.tail.len() - self.samples];
+
+                if depth > earlier {
+                    increases += 1;
+                }
+            }
+        }
+
+        let mut head = self.head;
+        head.extend(next.head.iter().take(N - head.len()));
+
+        let mut tail = self.tail;
+        tail.extend(next.tail);
+        tail.drain(..tail.len().saturating_sub(N));
+
+        Self {
+            lines: self.lines + next.lines,
+            samples: self.samples + next.samples,
+            increases,
+            head,
+            tail,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::io::Write;
+
+    use aoc_common::parse::ParseError;
+
+    use super::*;
+    use crate::{parser_simple::ParserSimple, parser_sliding_window::ParserSlidingWindow};
+
+    const INPUT: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    fn sequential<const N: usize>(input: &str) -> Result<u64, ParseError> {
+        let mut parser = ParserSlidingWindow::<N, u64>::new();
+
+        for (index, line) in input.lines().enumerate() {
+            parser.parse(line).at_line(index + 1)?;
+        }
+
+        Ok(parser.report().increases)
+    }
+
+    /// Counts with every chunk size from a single byte up to the whole input.
+    fn chunked<const N: usize>(input: &str) -> Vec<u64> {
+        (1..=input.len())
+            .map(|size| {
+                split(input.as_bytes(), size)
+                    .into_iter()
+                    .map(|chunk| Summary::<N>::new(chunk).unwrap())
+                    .fold(Summary::default(), Summary::merge)
+                    .increases
+            })
+            .collect()
+    }
+
+    #[test]
+    fn it_works() {
+        assert_eq!(count_increases::<1>(INPUT.as_bytes()).unwrap(), 7);
+        assert_eq!(count_increases::<3>(INPUT.as_bytes()).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_split() {
+        let chunks = split(INPUT.as_bytes(), 6);
+
+        assert_eq!(chunks.concat(), INPUT.as_bytes());
+        assert!(chunks.iter().all(|chunk| chunk.ends_with(b"\n")));
+        assert_eq!(chunks[0], b"199\n200\n");
+        assert!(split(b"", 6).is_empty());
+    }
+
+    #[test]
+    fn test_chunk_boundaries() {
+        let mut parser = ParserSimple::<u64>::new();
+        for line in INPUT.lines() {
+            parser.parse(line).unwrap();
+        }
+
+        for increases in chunked::<1>(INPUT) {
+            assert_eq!(increases, parser.report().increases);
+        }
+
+        let input = include_str!("../input.txt");
+        let input: String = input
+            .lines()
+            .take(200)
+            .map(|line| format!("{line}\n"))
+            .collect();
+
+        macro_rules! assert_windows {
+            ($($n:literal),*) => {
+                $(
+                    let expected = sequential::<$n>(&input).unwrap();
+                    assert!(chunked::<$n>(&input).iter().all(|&increases| increases == expected));
+                )*
+            };
+        }
+
+        assert_windows!(1, 2, 3, 7, 60);
+    }
+
+    #[test]
+    fn test_matches_sequential() {
+        let input = include_str!("../input.txt");
+
+        for size in [1, 64, 4096] {
+            let chunks = split(input.as_bytes(), size);
+            let summaries: Vec<_> = chunks
+                .iter()
+                .map(|c| Summary::<3>::new(c).unwrap())
+                .collect();
+            let total = summaries
+                .into_iter()
+                .fold(Summary::default(), Summary::merge);
+
+            assert_eq!(total.samples, input.lines().count());
+            assert_eq!(total.increases, sequential::<3>(input).unwrap());
+        }
+
+        assert_eq!(count_increases::<1>(input.as_bytes()).unwrap(), 1581);
+        assert_eq!(count_increases::<3>(input.as_bytes()).unwrap(), 1618);
+    }
+
+    #[test]
+    fn test_errors() {
+        let input = "199\n200\r\n\n208\n";
+
+        let Err(SweepError::Parse(error)) = count_increases::<3>(input.as_bytes()) else {
+            panic!("the empty line should not parse");
+        };
+        assert_eq!(error, sequential::<3>(input).unwrap_err());
+        assert_eq!(error.line, 3);
+
+        let mut input = b"1\n2\n3\n".repeat(CHUNK_SIZE / 3);
+        input.extend_from_slice(b"4\n\xff\n");
+
+        let Err(SweepError::InvalidUtf8 { line }) = count_increases::<1>(&input) else {
+            panic!("the last line is not UTF-8");
+        };
+        assert_eq!(line, 3 * (CHUNK_SIZE / 3) + 2);
+    }
+
+    #[test]
+    fn test_count_file_increases() {
+        let path = std::env::temp_dir().join(format!("day_1_sweep_{}.txt", std::process::id()));
+        File::create(&path)
+            .unwrap()
+            .write_all(INPUT.as_bytes())
+            .unwrap();
+
+        assert_eq!(count_file_increases::<3>(&path).unwrap(), 5);
+        std::fs::remove_file(&path).unwrap();
+
+        assert!(matches!(
+            count_file_increases::<3>(&path),
+            Err(SweepError::Map { .. })
+        ));
+    }
+}
@@ -1,34 +1,39 @@
-use std::num::ParseIntError;
+use aoc_common::parse::{self, ParseError};
 
-pub struct ParserSimple {
-    previous_value: Option<u64>,
-    increases: u64,
+use crate::{depth::Depth, sweep_report::SweepReport};
+
+pub struct ParserSimple<T = u64> {
+    report: SweepReport<T>,
 }
 
-impl ParserSimple {
+impl<T: Depth> ParserSimple<T> {
     pub fn new() -> Self {
         Self {
-            previous_value: None,
-            increases: 0,
+            report: SweepReport::new(),
         }
     }
 
-    pub fn parse(&mut self, line: &str) -> Result<(), ParseIntError> {
-        let value = line.parse::<u64>()?;
-
-        if let Some(previous) = self.previous_value {
-            if value > previous {
-                self.increases += 1;
-            }
-        }
+    pub fn parse(&mut self, line: &str) -> Result<(), ParseError> {
+        let value = parse::number::<T>(line)?;
 
-        self.previous_value.replace(value);
+        self.push(value);
 
         Ok(())
     }
 
-    pub fn get_increases(&self) -> u64 {
-        self.increases
+    pub fn push(&mut self, value: T) {
+        self.report.record(value);
+    }
+
+    /// Statistics of the depths parsed so far.
+    pub fn report(&self) -> &SweepReport<T> {
+        &self.report
+    }
+}
+
+impl<T: Depth> Default for ParserSimple<T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -38,7 +43,7 @@ mod tests {
 
     #[test]
     fn it_works() {
-        let mut parser = ParserSimple::new();
+        let mut parser = ParserSimple::<u64>::new();
 
         for line in vec![
             "199", "200", "208", "210", "200", "207", "240", "269", "260", "263",
@@ -46,6 +51,100 @@ mod tests {
             parser.parse(line).unwrap();
         }
 
-        assert_eq!(parser.get_increases(), 7);
+        assert_eq!(parser.report().increases, 7);
+        assert_eq!(parser.report().max, Some(269));
+    }
+
+    #[test]
+    fn test_signed() {
+        let mut parser = ParserSimple::<i64>::new();
+
+        for line in ["-3", "-1", "-7", "4"] {
+            parser.parse(line).unwrap();
+        }
+
+        assert_eq!(parser.report().increases, 2);
+        assert_eq!(parser.report().min, Some(-7));
+        assert!(parser.parse("-").is_err());
+    }
+
+    #[test]
+    fn test_fractional() {
+        let mut parser = ParserSimple::<f64>::new();
+
+        for line in ["199.5", "199.25", "NaN", "200", "200.75"] {
+            parser.parse(line).unwrap();
+        }
+
+        let report = parser.report();
+
+        assert_eq!(report.increases, 1);
+        assert_eq!(report.decreases, 1);
+        assert_eq!(report.incomparable, 2);
+        assert_eq!(report.max, Some(200.75));
+    }
+
+    /// Hundredths of a unit, read from a decimal with at most two fractional digits.
+    #[derive(Debug, Clone, Copy, PartialEq, PartialOrd)]
+    struct Centi(i64);
+
+    impl std::ops::Add for Centi {
+        type Output = Self;
+
+        fn add(self, other: Self) -> Self {
+            Self(self.0 + other.0)
+        }
+    }
+
+    impl std::ops::Sub for Centi {
+        type Output = Self;
+
+        fn sub(self, other: Self) -> Self {
+            Self(self.0 - other.0)
+        }
+    }
+
+    impl std::str::FromStr for Centi {
+        type Err = String;
+
+        fn from_str(s: &str) -> Result<Self, Self::Err> {
+            let (sign, s) = match s.strip_prefix('-') {
+                Some(s) => (-1, s),
+                None => (1, s),
+            };
+            let (whole, fraction) = s.split_once('.').unwrap_or((s, "0"));
+            let fraction = format!("{fraction:0<2}");
+
+            if fraction.len() > 2 {
+                return Err("too many fractional digits".to_string());
+            }
+
+            let whole = whole.parse::<i64>().map_err(|e| e.to_string())?;
+            let fraction = fraction.parse::<i64>().map_err(|e| e.to_string())?;
+
+            Ok(Self(sign * (whole * 100 + fraction)))
+        }
+    }
+
+    impl Depth for Centi {
+        const ZERO: Self = Self(0);
+        const EXACT: bool = true;
+
+        fn to_f64(self) -> f64 {
+            self.0 as f64 / 100.0
+        }
+    }
+
+    #[test]
+    fn test_fixed_point() {
+        let mut parser = ParserSimple::<Centi>::new();
+
+        for line in ["-0.5", "0.1", "0.2", "0.3", "0.25"] {
+            parser.parse(line).unwrap();
+        }
+
+        assert_eq!(parser.report().increases, 3);
+        assert_eq!(parser.report().largest_jump, Centi(60));
+        assert!(parser.parse("0.125").is_err());
     }
 }
@@ -1,46 +1,119 @@
-use std::{cmp::Ordering, collections::VecDeque, num::ParseIntError};
+use std::collections::VecDeque;
 
-pub struct ParserSlidingWindow {
-    previous_sum: Option<u64>,
-    increases: u64,
-    window: VecDeque<u64>,
+use aoc_common::parse::{self, ParseError};
+
+use crate::{depth::Depth, sweep_report::SweepReport};
+
+/// Compares the sums of consecutive windows of `N` samples.
+///
+/// The sum is updated as samples enter and leave the window,
+/// so every sample costs the same regardless of the window size.
+/// Sums of inexact types such as floats also carry the rounding error of every update,
+/// so a large sample leaving the window cannot take the small ones with it.
+pub struct ParserSlidingWindow<const N: usize, T = u64> {
+    report: SweepReport<T>,
+    window: VecDeque<T>,
+    sum: T,
+    /// Rounding error of `sum`, always zero for exact types
+    compensation: T,
 }
 
-impl ParserSlidingWindow {
+impl<const N: usize, T: Depth> ParserSlidingWindow<N, T> {
     pub fn new() -> Self {
+        const { assert!(N > 0, "the window needs at least one sample") };
+
         Self {
-            previous_sum: None,
-            increases: 0,
-            window: VecDeque::new(),
+            report: SweepReport::new(),
+            window: VecDeque::with_capacity(N),
+            sum: T::ZERO,
+            compensation: T::ZERO,
         }
     }
 
-    pub fn parse(&mut self, line: &str) -> Result<(), ParseIntError> {
-        let value = line.parse::<u64>()?;
+    pub fn parse(&mut self, line: &str) -> Result<(), ParseError> {
+        let value = parse::number::<T>(line)?;
+
+        self.push(value);
+
+        Ok(())
+    }
+
+    pub fn push(&mut self, value: T) {
+        // the oldest sample is part of the sum, so removing it first cannot overflow
+        if self.window.len() == N {
+            if let Some(oldest) = self.window.pop_front() {
+                self.subtract(oldest);
+            }
+        }
 
         self.window.push_back(value);
+        self.add(value);
 
-        match self.window.len().cmp(&3) {
-            Ordering::Less => return Ok(()),
-            Ordering::Greater => self.window.pop_front(),
-            Ordering::Equal => None,
-        };
+        if self.window.len() < N {
+            return;
+        }
 
-        let sum: u64 = self.window.iter().sum();
+        // a NaN poisons the running sum even after it leaves the window
+        if self.sum.partial_cmp(&self.sum).is_none() {
+            (self.sum, self.compensation) = (T::ZERO, T::ZERO);
 
-        if let Some(previous) = self.previous_sum {
-            if sum > previous {
-                self.increases += 1;
+            for index in 0..self.window.len() {
+                self.add(self.window[index]);
             }
         }
 
-        self.previous_sum.replace(sum);
+        self.report.record(self.sum + self.compensation);
+    }
 
-        Ok(())
+    /// Statistics of the window sums parsed so far.
+    pub fn report(&self) -> &SweepReport<T> {
+        &self.report
+    }
+}
+
+// Neumaier's compensated summation: the low-order bits that rounding drops from `sum`
+// are kept in `compensation`, taken from whichever operand has the smaller magnitude.
+impl<const N: usize, T: Depth> ParserSlidingWindow<N, T> {
+    fn add(&mut self, value: T) {
+        let sum = self.sum + value;
+
+        if !T::EXACT {
+            let error = if magnitude(self.sum) >= magnitude(value) {
+                (self.sum - sum) + value
+            } else {
+                (value - sum) + self.sum
+            };
+
+            self.compensation = self.compensation + error;
+        }
+
+        self.sum = sum;
     }
 
-    pub fn get_increases(&self) -> u64 {
-        self.increases
+    fn subtract(&mut self, value: T) {
+        let sum = self.sum - value;
+
+        if !T::EXACT {
+            let error = if magnitude(self.sum) >= magnitude(value) {
+                (self.sum - sum) - value
+            } else {
+                self.sum - (sum + value)
+            };
+
+            self.compensation = self.compensation + error;
+        }
+
+        self.sum = sum;
+    }
+}
+
+fn magnitude<T: Depth>(value: T) -> T {
+    value.distance(T::ZERO)
+}
+
+impl<const N: usize, T: Depth> Default for ParserSlidingWindow<N, T> {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -48,16 +121,111 @@ impl ParserSlidingWindow {
 mod tests {
     use super::*;
 
+    const INPUT: [&str; 10] = [
+        "199", "200", "208", "210", "200", "207", "240", "269", "260", "263",
+    ];
+
+    fn increases<const N: usize>() -> u64 {
+        let mut parser = ParserSlidingWindow::<N, u64>::new();
+
+        for line in INPUT {
+            parser.parse(line).unwrap();
+        }
+
+        parser.report().increases
+    }
+
     #[test]
     fn it_works() {
-        let mut parser = ParserSlidingWindow::new();
+        assert_eq!(increases::<3>(), 5);
+    }
+
+    #[test]
+    fn test_window_sizes() {
+        assert_eq!(increases::<1>(), 7);
+        assert_eq!(increases::<2>(), 5);
+        assert_eq!(increases::<9>(), 1);
+        assert_eq!(increases::<10>(), 0);
+        assert_eq!(increases::<60>(), 0);
+    }
+
+    #[test]
+    fn test_report() {
+        let mut parser = ParserSlidingWindow::<3, u64>::new();
+
+        for line in INPUT {
+            parser.parse(line).unwrap();
+        }
 
-        for line in vec![
-            "199", "200", "208", "210", "200", "207", "240", "269", "260", "263",
-        ] {
+        let report = parser.report();
+
+        assert_eq!(report.samples, 8);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.longest_increase_run, 4);
+        assert_eq!(report.min, Some(607));
+        assert_eq!(report.max, Some(792));
+    }
+
+    #[test]
+    fn test_nan_leaves_window() {
+        let mut parser = ParserSlidingWindow::<2, f64>::new();
+
+        for line in ["1.5", "NaN", "2", "3", "-0.5", "4"] {
             parser.parse(line).unwrap();
         }
 
-        assert_eq!(parser.get_increases(), 5);
+        let report = parser.report();
+
+        assert_eq!(report.samples, 5);
+        assert_eq!(report.incomparable, 2);
+        assert_eq!(report.increases, 1);
+        assert_eq!(report.decreases, 1);
+        assert_eq!(report.min, Some(2.5));
+    }
+
+    #[test]
+    fn test_wide_window() {
+        let mut parser = ParserSlidingWindow::<60, f64>::new();
+
+        parser.push(1e16);
+        for value in [1.0, 2.0, 0.5].repeat(100) {
+            parser.push(value);
+        }
+
+        let report = parser.report();
+
+        assert_eq!(report.samples, 242);
+        assert_eq!(report.decreases, 1);
+        assert_eq!(report.unchanged, 240);
+        assert_eq!(report.min, Some(70.0));
+    }
+
+    #[test]
+    fn test_sum_near_max() {
+        let mut parser = ParserSlidingWindow::<2, u64>::new();
+
+        for value in [u64::MAX - 1, 1, 1] {
+            parser.push(value);
+        }
+
+        let report = parser.report();
+
+        assert_eq!(report.max, Some(u64::MAX));
+        assert_eq!(report.min, Some(2));
+    }
+
+    #[test]
+    fn test_large_sample_leaves_window() {
+        let mut parser = ParserSlidingWindow::<2, f64>::new();
+
+        for value in [1e16, 1.0, 1.0, 1.0] {
+            parser.push(value);
+        }
+
+        let report = parser.report();
+
+        assert_eq!(report.decreases, 1);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.min, Some(2.0));
     }
 }
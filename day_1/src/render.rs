@@ -0,0 +1,196 @@
+use std::{fmt::Write, fs, io, path::Path};
+
+use crate::depth::Depth;
+
+const WIDTH: f64 = 960.0;
+const PANEL_HEIGHT: f64 = 240.0;
+const MARGIN: f64 = 48.0;
+
+const LINE_COLOUR: &str = "#4a6fa5";
+const INCREASE_COLOUR: &str = "#e4572e";
+
+/// Line chart of a sonar sweep, rendered as SVG.
+///
+/// The raw depths and their sliding window sums are drawn in two stacked panels,
+/// each with its own vertical scale. Segments where the value increased are highlighted,
+/// and samples that cannot be plotted, such as a `NaN` float, leave a gap in the line.
+pub struct DepthProfile {
+    depths: Vec<f64>,
+    sums: Vec<f64>,
+    window: usize,
+}
+
+impl DepthProfile {
+    /// A `window` of 0 only draws the raw depths.
+    pub fn new<T: Depth>(depths: impl IntoIterator<Item = T>, window: usize) -> Self {
+        let depths: Vec<f64> = depths.into_iter().map(Depth::to_f64).collect();
+
+        let sums = match window {
+            0 => Vec::new(),
+            _ => depths
+                .windows(window)
+                .map(|values| values.iter().sum())
+                .collect(),
+        };
+
+        Self {
+            depths,
+            sums,
+            window,
+        }
+    }
+
+    pub fn to_svg(&self) -> String {
+        let panels = if self.window == 0 { 1.0 } else { 2.0 };
+        let height = panels * (PANEL_HEIGHT + MARGIN) + MARGIN;
+
+        let mut svg = String::new();
+
+        // writing to a `String` cannot fail
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" width="{WIDTH}" height="{height}" viewBox="0 0 {WIDTH} {height}" font-family="sans-serif" font-size="12">"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<rect width="{WIDTH}" height="{height}" fill="white"/>"#
+        );
+
+        self.panel(&mut svg, "depth", &self.depths, MARGIN);
+
+        if self.window > 0 {
+            let title = format!("sum of {} depths", self.window);
+            self.panel(&mut svg, &title, &self.sums, 2.0 * MARGIN + PANEL_HEIGHT);
+        }
+
+        svg.push_str("</svg>\n");
+        svg
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        fs::write(path, self.to_svg())
+    }
+
+    fn panel(&self, svg: &mut String, title: &str, values: &[f64], top: f64) {
+        let left = MARGIN;
+        let right = WIDTH - MARGIN;
+        let bottom = top + PANEL_HEIGHT;
+
+        let _ = writeln!(svg, r#"<text x="{left}" y="{}">{title}</text>"#, top - 8.0);
+        let _ = writeln!(
+            svg,
+            r##"<rect x="{left}" y="{top}" width="{}" height="{PANEL_HEIGHT}" fill="none" stroke="#ccc"/>"##,
+            right - left
+        );
+
+        let finite = values.iter().copied().filter(|value| value.is_finite());
+        let Some((min, max)) = finite.fold(None, |bounds, value| match bounds {
+            None => Some((value, value)),
+            Some((min, max)) => Some((f64::min(min, value), f64::max(max, value))),
+        }) else {
+            return;
+        };
+
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{top}" text-anchor="end" dominant-baseline="hanging">{max}</text>"#,
+            left - 4.0
+        );
+        let _ = writeln!(
+            svg,
+            r#"<text x="{}" y="{bottom}" text-anchor="end">{min}</text>"#,
+            left - 4.0
+        );
+
+        // a flat sweep is drawn through the middle of the panel
+        let range = if max > min { max - min } else { 1.0 };
+        let offset = if max > min { 0.0 } else { 0.5 };
+        let step = (right - left) / values.len().saturating_sub(1).max(1) as f64;
+
+        let point = |index: usize, value: f64| {
+            let x = left + index as f64 * step;
+            let y = bottom - ((value - min) / range + offset) * PANEL_HEIGHT;
+            (x, y)
+        };
+
+        let mut line = String::new();
+        let mut increases = String::new();
+
+        for (index, pair) in values.windows(2).enumerate() {
+            let (previous, value) = (pair[0], pair[1]);
+
+            if !previous.is_finite() || !value.is_finite() {
+                continue;
+            }
+
+            let (x1, y1) = point(index, previous);
+            let (x2, y2) = point(index + 1, value);
+
+            let path = if value > previous {
+                &mut increases
+            } else {
+                &mut line
+            };
+            let _ = write!(path, "M{x1:.1} {y1:.1}L{x2:.1} {y2:.1}");
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<path class="line" d="{line}" fill="none" stroke="{LINE_COLOUR}" stroke-width="1"/>"#
+        );
+        let _ = writeln!(
+            svg,
+            r#"<path class="increase" d="{increases}" fill="none" stroke="{INCREASE_COLOUR}" stroke-width="1.5"/>"#
+        );
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const DEPTHS: [u64; 10] = [199, 200, 208, 210, 200, 207, 240, 269, 260, 263];
+
+    fn segments(svg: &str, class: &str) -> Vec<usize> {
+        svg.lines()
+            .filter(|line| line.contains(&format!(r#"class="{class}""#)))
+            .map(|line| line.matches('M').count())
+            .collect()
+    }
+
+    #[test]
+    fn test_increases_are_highlighted() {
+        let svg = DepthProfile::new(DEPTHS, 3).to_svg();
+
+        assert!(svg.starts_with("<svg"));
+        assert!(svg.contains(">sum of 3 depths</text>"));
+        assert_eq!(segments(&svg, "increase"), [7, 5]);
+        assert_eq!(segments(&svg, "line"), [2, 2]);
+    }
+
+    #[test]
+    fn test_depths_only() {
+        let svg = DepthProfile::new(DEPTHS, 0).to_svg();
+
+        assert!(!svg.contains("sum of"));
+        assert_eq!(segments(&svg, "increase"), [7]);
+    }
+
+    #[test]
+    fn test_gaps() {
+        let svg = DepthProfile::new([1.0, f64::NAN, 2.0, 3.0, 2.5], 0).to_svg();
+
+        assert_eq!(segments(&svg, "increase"), [1]);
+        assert_eq!(segments(&svg, "line"), [1]);
+    }
+
+    #[test]
+    fn test_degenerate_sweeps() {
+        for depths in [vec![], vec![5], vec![5, 5, 5]] {
+            let svg = DepthProfile::new(depths, 3).to_svg();
+
+            assert!(svg.ends_with("</svg>\n"));
+            assert!(!svg.contains("NaN"));
+        }
+    }
+}
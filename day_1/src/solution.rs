@@ -0,0 +1,40 @@
+use std::error::Error;
+
+use aoc_common::Solution;
+
+use crate::parallel;
+
+// https://adventofcode.com/2021/day/1
+pub struct Day1;
+
+impl Solution for Day1 {
+    const DAY: u8 = 1;
+
+    type Part1 = u64;
+    type Part2 = u64;
+
+    fn part_1(input: &str) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(parallel::count_increases::<1>(input.as_bytes())?)
+    }
+
+    fn part_2(input: &str) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(parallel::count_increases::<3>(input.as_bytes())?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "199\n200\n208\n210\n200\n207\n240\n269\n260\n263\n";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day1::part_1(INPUT).unwrap(), 7);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day1::part_2(INPUT).unwrap(), 5);
+    }
+}
@@ -0,0 +1,231 @@
+use core::{cmp::Ordering, fmt};
+
+use crate::depth::Depth;
+
+/// Statistics of a sonar sweep, built one sample at a time.
+///
+/// The samples are either raw depths or sliding window sums.
+#[derive(Debug, Clone, PartialEq)]
+pub struct SweepReport<T = u64> {
+    pub samples: u64,
+    pub increases: u64,
+    pub decreases: u64,
+    pub unchanged: u64,
+    /// Consecutive samples that cannot be compared, such as a `NaN` float
+    pub incomparable: u64,
+    /// Most consecutive increases in a row
+    pub longest_increase_run: u64,
+    /// Largest difference between two consecutive samples, in either direction
+    pub largest_jump: T,
+    pub min: Option<T>,
+    pub max: Option<T>,
+    total: f64,
+    previous: Option<T>,
+    increase_run: u64,
+}
+
+impl<T: Depth> SweepReport<T> {
+    pub fn new() -> Self {
+        Self {
+            samples: 0,
+            increases: 0,
+            decreases: 0,
+            unchanged: 0,
+            incomparable: 0,
+            longest_increase_run: 0,
+            largest_jump: T::ZERO,
+            min: None,
+            max: None,
+            total: 0.0,
+            previous: None,
+            increase_run: 0,
+        }
+    }
+
+    pub fn record(&mut self, value: T) {
+        if let Some(previous) = self.previous {
+            let ordering = value.partial_cmp(&previous);
+
+            match ordering {
+                Some(Ordering::Greater) => {
+                    self.increases += 1;
+                    self.increase_run += 1;
+                    self.longest_increase_run = self.longest_increase_run.max(self.increase_run);
+                }
+                Some(Ordering::Less) => self.decreases += 1,
+                Some(Ordering::Equal) => self.unchanged += 1,
+                None => self.incomparable += 1,
+            }
+
+            if ordering != Some(Ordering::Greater) {
+                self.increase_run = 0;
+            }
+
+            let jump = match ordering {
+                Some(Ordering::Greater | Ordering::Less) => value.distance(previous),
+                _ => T::ZERO,
+            };
+
+            if jump > self.largest_jump {
+                self.largest_jump = jump;
+            }
+        }
+
+        self.samples += 1;
+        self.total += value.to_f64();
+        self.previous = Some(value);
+
+        // like `f64::min` and `f64::max`, incomparable values never replace a bound
+        if value.partial_cmp(&value).is_some() {
+            if self.min.is_none_or(|min| value < min) {
+                self.min = Some(value);
+            }
+
+            if self.max.is_none_or(|max| value > max) {
+                self.max = Some(value);
+            }
+        }
+    }
+
+    pub fn mean(&self) -> Option<f64> {
+        if self.samples == 0 {
+            return None;
+        }
+
+        Some(self.total / self.samples as f64)
+    }
+}
+
+impl<T: Depth> Default for SweepReport<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T: Depth + fmt::Display> fmt::Display for SweepReport<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "samples: {}", self.samples)?;
+        writeln!(f, "increases: {}", self.increases)?;
+        writeln!(f, "decreases: {}", self.decreases)?;
+        writeln!(f, "unchanged: {}", self.unchanged)?;
+        if self.incomparable > 0 {
+            writeln!(f, "incomparable: {}", self.incomparable)?;
+        }
+        writeln!(f, "longest increase run: {}", self.longest_increase_run)?;
+        writeln!(f, "largest jump: {}", self.largest_jump)?;
+
+        match (self.min, self.max, self.mean()) {
+            (Some(min), Some(max), Some(mean)) => {
+                writeln!(f, "min: {min}")?;
+                writeln!(f, "max: {max}")?;
+                write!(f, "mean: {mean:.2}")
+            }
+            _ => write!(f, "min: -\nmax: -\nmean: -"),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_record() {
+        let mut report = SweepReport::new();
+
+        for value in [199, 200, 208, 210, 200, 207, 240, 269, 260, 263] {
+            report.record(value);
+        }
+
+        assert_eq!(report.samples, 10);
+        assert_eq!(report.increases, 7);
+        assert_eq!(report.decreases, 2);
+        assert_eq!(report.unchanged, 0);
+        assert_eq!(report.longest_increase_run, 3);
+        assert_eq!(report.largest_jump, 33);
+        assert_eq!(report.min, Some(199));
+        assert_eq!(report.max, Some(269));
+        assert_eq!(report.mean(), Some(225.6));
+    }
+
+    #[test]
+    fn test_extreme_jumps() {
+        let mut report = SweepReport::new();
+
+        for value in [i64::MIN, i64::MAX, i64::MIN] {
+            report.record(value);
+        }
+
+        assert_eq!(report.largest_jump, i64::MAX);
+        assert_eq!((report.increases, report.decreases), (1, 1));
+
+        let mut report = SweepReport::new();
+
+        for value in [u64::MAX, 0] {
+            report.record(value);
+        }
+
+        assert_eq!(report.largest_jump, u64::MAX);
+    }
+
+    #[test]
+    fn test_display() {
+        let mut report = SweepReport::<u64>::new();
+
+        for value in [607, 618, 618, 617, 647, 716, 769, 792] {
+            report.record(value);
+        }
+
+        assert_eq!(
+            report.to_string(),
+            "samples: 8\n\
+             increases: 5\n\
+             decreases: 1\n\
+             unchanged: 1\n\
+             longest increase run: 4\n\
+             largest jump: 69\n\
+             min: 607\n\
+             max: 792\n\
+             mean: 673.00"
+        );
+    }
+
+    #[test]
+    fn test_empty() {
+        let report = SweepReport::<u64>::new();
+
+        assert_eq!(report.mean(), None);
+        assert!(report.to_string().ends_with("min: -\nmax: -\nmean: -"));
+    }
+
+    #[test]
+    fn test_signed() {
+        let mut report = SweepReport::new();
+
+        for value in [-3, 2, -10, -10] {
+            report.record(value);
+        }
+
+        assert_eq!(report.increases, 1);
+        assert_eq!(report.decreases, 1);
+        assert_eq!(report.unchanged, 1);
+        assert_eq!(report.largest_jump, 12);
+        assert_eq!(report.min, Some(-10));
+    }
+
+    #[test]
+    fn test_nan() {
+        let mut report = SweepReport::new();
+
+        for value in [1.5, f64::NAN, 2.5, 3.0] {
+            report.record(value);
+        }
+
+        assert_eq!(report.increases, 1);
+        assert_eq!(report.incomparable, 2);
+        assert_eq!(report.largest_jump, 0.5);
+        assert_eq!(report.min, Some(1.5));
+        assert_eq!(report.max, Some(3.0));
+        assert!(report.mean().unwrap().is_nan());
+    }
+}
@@ -0,0 +1,184 @@
+//! A compact encoding of courses: every command is a 1-byte opcode,
+//! followed by its value as an unsigned LEB128 varint when it has one.
+
+use crate::{command::Command, error::DecodeError};
+
+const FORWARD: u8 = 0;
+const DOWN: u8 = 1;
+const UP: u8 = 2;
+const BACK: u8 = 3;
+const HOLD: u8 = 4;
+const RESET_AIM: u8 = 5;
+const SURFACE: u8 = 6;
+const LEFT: u8 = 7;
+const RIGHT: u8 = 8;
+
+impl Command {
+    pub fn encode(&self, output: &mut Vec<u8>) {
+        let (opcode, value) = match self {
+            Command::Forward(value) => (FORWARD, Some(value)),
+            Command::Down(value) => (DOWN, Some(value)),
+            Command::Up(value) => (UP, Some(value)),
+            Command::Back(value) => (BACK, Some(value)),
+            Command::Hold(value) => (HOLD, Some(value)),
+            Command::ResetAim => (RESET_AIM, None),
+            Command::Surface => (SURFACE, None),
+            Command::Left(value) => (LEFT, Some(value)),
+            Command::Right(value) => (RIGHT, Some(value)),
+        };
+
+        output.push(opcode);
+
+        if let Some(&value) = value {
+            encode_varint(value, output);
+        }
+    }
+
+    /// Decodes the command at the start of `input`, along with the number of bytes it took.
+    pub fn decode(input: &[u8]) -> Result<(Command, usize), DecodeError> {
+        let (&opcode, rest) = input
+            .split_first()
+            .ok_or(DecodeError::Truncated { offset: 0 })?;
+
+        let value = || decode_varint(rest).map_err(|error| error.at(1));
+
+        let (command, length) = match opcode {
+            FORWARD => value().map(|(value, length)| (Command::Forward(value), length))?,
+            DOWN => value().map(|(value, length)| (Command::Down(value), length))?,
+            UP => value().map(|(value, length)| (Command::Up(value), length))?,
+            BACK => value().map(|(value, length)| (Command::Back(value), length))?,
+            HOLD => value().map(|(value, length)| (Command::Hold(value), length))?,
+            RESET_AIM => (Command::ResetAim, 0),
+            SURFACE => (Command::Surface, 0),
+            LEFT => value().map(|(value, length)| (Command::Left(value), length))?,
+            RIGHT => value().map(|(value, length)| (Command::Right(value), length))?,
+            _ => return Err(DecodeError::UnknownOpcode { opcode, offset: 0 }),
+        };
+
+        Ok((command, length + 1))
+    }
+}
+
+pub fn encode_course<'a>(commands: impl IntoIterator<Item = &'a Command>) -> Vec<u8> {
+    let mut output = Vec::new();
+
+    for command in commands {
+        command.encode(&mut output);
+    }
+
+    output
+}
+
+/// Fails with the offset of the first byte that cannot be decoded.
+pub fn decode_course(mut input: &[u8]) -> Result<Vec<Command>, DecodeError> {
+    let mut commands = Vec::new();
+    let mut offset = 0;
+
+    while !input.is_empty() {
+        let (command, length) = Command::decode(input).map_err(|error| error.at(offset))?;
+
+        commands.push(command);
+        input = &input[length..];
+        offset += length;
+    }
+
+    Ok(commands)
+}
+
+fn encode_varint(mut value: u64, output: &mut Vec<u8>) {
+    while value >= 0x80 {
+        output.push(value as u8 | 0x80);
+        value >>= 7;
+    }
+
+    output.push(value as u8);
+}
+
+fn decode_varint(input: &[u8]) -> Result<(u64, usize), DecodeError> {
+    let mut value = 0u64;
+
+    for (index, &byte) in input.iter().enumerate() {
+        let bits = u64::from(byte & 0x7f);
+        let shift = 7 * index as u32;
+
+        // the tenth byte only has room for the top bit of a u64
+        if shift >= 64 || (shift == 63 && bits > 1) {
+            return Err(DecodeError::VarintOverflow { offset: index });
+        }
+
+        value |= bits << shift;
+
+        if byte & 0x80 == 0 {
+            return Ok((value, index + 1));
+        }
+    }
+
+    Err(DecodeError::Truncated {
+        offset: input.len(),
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::command::tests::command;
+
+    proptest! {
+        #[test]
+        fn test_round_trip(command in command()) {
+            let mut bytes = Vec::new();
+            command.encode(&mut bytes);
+
+            prop_assert_eq!(Command::decode(&bytes), Ok((command, bytes.len())));
+        }
+
+        #[test]
+        fn test_course_round_trip(commands in prop::collection::vec(command(), 0..32)) {
+            let bytes = encode_course(&commands);
+
+            prop_assert_eq!(decode_course(&bytes), Ok(commands));
+        }
+    }
+
+    #[test]
+    fn test_encoding() {
+        let commands = [
+            Command::Forward(5),
+            Command::Down(300),
+            Command::Surface,
+            Command::Up(u64::MAX),
+        ];
+
+        assert_eq!(
+            encode_course(&commands),
+            [
+                [0, 5].as_slice(),
+                &[1, 0xac, 0x02],
+                &[6],
+                &[2, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x01],
+            ]
+            .concat()
+        );
+    }
+
+    #[test]
+    fn test_errors() {
+        assert_eq!(
+            decode_course(&[6, 9]),
+            Err(DecodeError::UnknownOpcode {
+                opcode: 9,
+                offset: 1
+            })
+        );
+        assert_eq!(
+            decode_course(&[0, 5, 1, 0x80]),
+            Err(DecodeError::Truncated { offset: 4 })
+        );
+        assert_eq!(
+            decode_course(&[2, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0xff, 0x02]),
+            Err(DecodeError::VarintOverflow { offset: 10 })
+        );
+    }
+}
@@ -1,35 +1,263 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-use anyhow::Context;
+use aoc_common::parse::{self, AtLine, ParseError, ParseErrorKind};
+use serde::{de, Deserialize, Deserializer, Serialize, Serializer};
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub enum Command {
     Forward(u64),
     Down(u64),
     Up(u64),
+    Back(u64),
+    /// Forward, keeping the current depth whatever the aim
+    Hold(u64),
+    ResetAim,
+    Surface,
+    /// Turns the heading to port, in degrees
+    Left(u64),
+    /// Turns the heading to starboard, in degrees
+    Right(u64),
 }
 
+impl Command {
+    /// The first grammar version that has this command.
+    pub fn grammar(&self) -> Grammar {
+        match self {
+            Command::Forward(_) | Command::Down(_) | Command::Up(_) => Grammar::V1,
+            Command::Back(_) | Command::Hold(_) | Command::ResetAim | Command::Surface => {
+                Grammar::V2
+            }
+            Command::Left(_) | Command::Right(_) => Grammar::V3,
+        }
+    }
+}
+
+/// Parses any command of the latest grammar, see [`Grammar::parse`] to stick to a version.
 impl FromStr for Command {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut split = s.split_whitespace();
-        let command = split.next().context("command not found")?;
-        let value = split.next().context("value not found")?.parse::<u64>()?;
+        let mut fields = s.split_whitespace();
+        let command = parse::field(s, &mut fields, "command")?;
+
+        let mut value = || parse::integer_in(s, parse::field(s, &mut fields, "value")?);
+
+        let result = match command {
+            "forward" => Command::Forward(value()?),
+            "down" => Command::Down(value()?),
+            "up" => Command::Up(value()?),
+            "back" => Command::Back(value()?),
+            "hold" => Command::Hold(value()?),
+            "reset-aim" => Command::ResetAim,
+            "surface" => Command::Surface,
+            "left" => Command::Left(value()?),
+            "right" => Command::Right(value()?),
+            _ => {
+                let kind = ParseErrorKind::InvalidValue {
+                    name: "command",
+                    value: command.to_string(),
+                };
+
+                return Err(ParseError::new(s, command, kind));
+            }
+        };
+
+        parse::finish(s, &mut fields)?;
+
+        Ok(result)
+    }
+}
+
+impl fmt::Display for Command {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Command::Forward(value) => write!(f, "forward {value}"),
+            Command::Down(value) => write!(f, "down {value}"),
+            Command::Up(value) => write!(f, "up {value}"),
+            Command::Back(value) => write!(f, "back {value}"),
+            Command::Hold(value) => write!(f, "hold {value}"),
+            Command::ResetAim => write!(f, "reset-aim"),
+            Command::Surface => write!(f, "surface"),
+            Command::Left(value) => write!(f, "left {value}"),
+            Command::Right(value) => write!(f, "right {value}"),
+        }
+    }
+}
 
-        match command {
-            "forward" => Ok(Command::Forward(value)),
-            "down" => Ok(Command::Down(value)),
-            "up" => Ok(Command::Up(value)),
-            _ => Err(anyhow::anyhow!("unrecognized command {command}")),
+/// Serialized as the text of the command, such as `"forward 5"`.
+impl Serialize for Command {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.collect_str(self)
+    }
+}
+
+impl<'de> Deserialize<'de> for Command {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let text = String::deserialize(deserializer)?;
+
+        Command::from_str(&text).map_err(|e| de::Error::custom(e.kind))
+    }
+}
+
+/// Version of the course file format, declared by a `grammar N` header on the first line.
+///
+/// Files without a header are version 1, which only has `forward`, `down` and `up`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord)]
+pub enum Grammar {
+    #[default]
+    V1,
+    /// Adds `back`, `hold`, `reset-aim` and `surface`
+    V2,
+    /// Adds `left` and `right`
+    V3,
+}
+
+impl Grammar {
+    /// The version declared by a header line, `None` when the line is not a header.
+    pub fn from_header(line: &str) -> Result<Option<Grammar>, ParseError> {
+        let mut fields = line.split_whitespace();
+
+        if fields.next() != Some("grammar") {
+            return Ok(None);
         }
+
+        let token = parse::field(line, &mut fields, "version")?;
+        parse::finish(line, &mut fields)?;
+
+        match parse::integer_in::<u32>(line, token)? {
+            1 => Ok(Some(Grammar::V1)),
+            2 => Ok(Some(Grammar::V2)),
+            3 => Ok(Some(Grammar::V3)),
+            _ => {
+                let kind = ParseErrorKind::InvalidValue {
+                    name: "grammar version",
+                    value: token.to_string(),
+                };
+
+                Err(ParseError::new(line, token, kind))
+            }
+        }
+    }
+
+    /// Parses a command, rejecting the ones that are newer than this version.
+    pub fn parse(self, line: &str) -> Result<Command, ParseError> {
+        let command = Command::from_str(line)?;
+
+        if command.grammar() > self {
+            let token = line.trim_start();
+            let token = &token[..token.find(char::is_whitespace).unwrap_or(token.len())];
+
+            let kind = ParseErrorKind::Unsupported {
+                value: token.to_string(),
+                reason: format!("needs a `grammar {}` header", command.grammar()),
+            };
+
+            return Err(ParseError::new(line, token, kind));
+        }
+
+        Ok(command)
+    }
+}
+
+impl fmt::Display for Grammar {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Grammar::V1 => write!(f, "1"),
+            Grammar::V2 => write!(f, "2"),
+            Grammar::V3 => write!(f, "3"),
+        }
+    }
+}
+
+/// Parses a course file, one command per line after an optional header,
+/// along with the 1-based line of each command.
+pub fn parse_course(
+    input: &str,
+) -> impl Iterator<Item = Result<(usize, Command), ParseError>> + '_ {
+    let mut grammar = Grammar::default();
+
+    input.lines().enumerate().filter_map(move |(index, line)| {
+        if index == 0 {
+            match Grammar::from_header(line).at_line(1) {
+                Ok(Some(version)) => {
+                    grammar = version;
+                    return None;
+                }
+                Ok(None) => {}
+                Err(error) => return Some(Err(error)),
+            }
+        }
+
+        let command = grammar.parse(line).at_line(index + 1);
+
+        Some(command.map(|command| (index + 1, command)))
+    })
+}
+
+/// Writes a course that [`parse_course`] reads back, with a header when it needs a newer grammar.
+pub fn format_course<'a>(commands: impl IntoIterator<Item = &'a Command>) -> String {
+    let mut grammar = Grammar::default();
+    let mut body = String::new();
+
+    for command in commands {
+        grammar = grammar.max(command.grammar());
+
+        body.push_str(&command.to_string());
+        body.push('\n');
+    }
+
+    match grammar {
+        Grammar::V1 => body,
+        _ => format!("grammar {grammar}\n{body}"),
     }
 }
 
 #[cfg(test)]
-mod tests {
+pub(crate) mod tests {
+    use proptest::prelude::*;
+
     use super::*;
 
+    pub(crate) fn command() -> impl Strategy<Value = Command> {
+        prop_oneof![
+            any::<u64>().prop_map(Command::Forward),
+            any::<u64>().prop_map(Command::Down),
+            any::<u64>().prop_map(Command::Up),
+            any::<u64>().prop_map(Command::Back),
+            any::<u64>().prop_map(Command::Hold),
+            Just(Command::ResetAim),
+            Just(Command::Surface),
+            any::<u64>().prop_map(Command::Left),
+            any::<u64>().prop_map(Command::Right),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_format_round_trip(command in command()) {
+            prop_assert_eq!(Command::from_str(&command.to_string()), Ok(command));
+        }
+
+        #[test]
+        fn test_serde_round_trip(command in command()) {
+            let json = serde_json::to_string(&command).unwrap();
+
+            prop_assert_eq!(json.clone(), format!("{:?}", command.to_string()));
+            prop_assert_eq!(serde_json::from_str::<Command>(&json).unwrap(), command);
+        }
+
+        #[test]
+        fn test_course_round_trip(commands in prop::collection::vec(command(), 0..32)) {
+            let course = format_course(&commands);
+            let parsed: Vec<_> = parse_course(&course)
+                .map(|command| command.map(|(_, command)| command))
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            prop_assert_eq!(parsed, commands);
+        }
+    }
+
     #[test]
     fn test_forward_try_into() {
         let command = Command::from_str("forward 8").unwrap();
@@ -47,4 +275,97 @@ mod tests {
         let command = Command::from_str("up 3").unwrap();
         assert_eq!(command, Command::Up(3));
     }
+
+    #[test]
+    fn test_display() {
+        for line in ["forward 8", "down 5", "up 3"] {
+            assert_eq!(Command::from_str(line).unwrap().to_string(), line);
+        }
+    }
+
+    #[test]
+    fn test_extended_commands() {
+        assert_eq!(Command::from_str("back 4").unwrap(), Command::Back(4));
+        assert_eq!(Command::from_str("hold 2").unwrap(), Command::Hold(2));
+        assert_eq!(Command::from_str("reset-aim").unwrap(), Command::ResetAim);
+        assert_eq!(Command::from_str(" surface ").unwrap(), Command::Surface);
+
+        for line in ["back 4", "hold 2", "reset-aim", "surface"] {
+            assert_eq!(Command::from_str(line).unwrap().to_string(), line);
+        }
+
+        assert!(Command::from_str("surface 3").is_err());
+        assert!(Command::from_str("hold").is_err());
+    }
+
+    #[test]
+    fn test_grammar_header() {
+        assert_eq!(Grammar::from_header("grammar 2"), Ok(Some(Grammar::V2)));
+        assert_eq!(Grammar::from_header("forward 2"), Ok(None));
+
+        let error = Grammar::from_header("grammar 4").unwrap_err();
+        assert_eq!(error.column, 9);
+        assert!(Grammar::from_header("grammar").is_err());
+    }
+
+    #[test]
+    fn test_parse_course() {
+        let old = "forward 5\ndown 5\n";
+        let commands: Vec<_> = parse_course(old).map(Result::unwrap).collect();
+
+        assert_eq!(commands, [(1, Command::Forward(5)), (2, Command::Down(5))]);
+
+        let new = "grammar 2\nforward 5\nsurface\n";
+        let commands: Vec<_> = parse_course(new).map(Result::unwrap).collect();
+
+        assert_eq!(commands, [(2, Command::Forward(5)), (3, Command::Surface)]);
+    }
+
+    #[test]
+    fn test_parse_course_needs_header() {
+        let errors: Vec<_> = parse_course("forward 5\n  back 2\n")
+            .filter_map(Result::err)
+            .collect();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(errors[0].line, 2);
+        assert_eq!(errors[0].column, 3);
+        assert_eq!(
+            errors[0].kind.to_string(),
+            "unsupported \"back\": needs a `grammar 2` header"
+        );
+    }
+
+    #[test]
+    fn test_format_course() {
+        let old = [Command::Forward(5), Command::Down(5)];
+        assert_eq!(format_course(&old), "forward 5\ndown 5\n");
+
+        let new = [Command::Forward(5), Command::Surface];
+        assert_eq!(format_course(&new), "grammar 2\nforward 5\nsurface\n");
+    }
+
+    #[test]
+    fn test_deserialize_errors() {
+        let error = serde_json::from_str::<Command>("\"sideways 3\"").unwrap_err();
+
+        assert_eq!(error.to_string(), "invalid command \"sideways\"");
+    }
+
+    #[test]
+    fn test_turn_commands() {
+        assert_eq!(Command::from_str("left 90").unwrap(), Command::Left(90));
+        assert_eq!(Command::from_str("right 45").unwrap(), Command::Right(45));
+
+        let errors: Vec<_> = parse_course("grammar 2\nsurface\nright 90\n")
+            .filter_map(Result::err)
+            .collect();
+
+        assert_eq!(errors.len(), 1);
+        assert_eq!(
+            errors[0].kind.to_string(),
+            "unsupported \"right\": needs a `grammar 3` header"
+        );
+        assert_eq!(format_course(&[Command::Left(90)]), "grammar 3\nleft 90\n");
+    }
 }
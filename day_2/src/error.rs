@@ -0,0 +1,47 @@
+use aoc_common::parse::ParseError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum NavigationError {
+    #[error("the course takes the submarine above the surface, to depth {depth}")]
+    AboveSurface { depth: i64 },
+    #[error("the course overflows the position of the submarine")]
+    Overflow,
+}
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum CourseError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error("line {line}: {error}")]
+    Navigation { line: usize, error: NavigationError },
+}
+
+/// A failure to decode a binary course, at the offset of the offending byte.
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DecodeError {
+    #[error("unknown opcode {opcode} at byte {offset}")]
+    UnknownOpcode { opcode: u8, offset: usize },
+    #[error("the course ends in the middle of a command, at byte {offset}")]
+    Truncated { offset: usize },
+    #[error("the value at byte {offset} does not fit in 64 bits")]
+    VarintOverflow { offset: usize },
+}
+
+impl DecodeError {
+    /// Moves the offset by `offset` bytes, for errors decoded from the middle of a course.
+    pub fn at(self, offset: usize) -> Self {
+        match self {
+            DecodeError::UnknownOpcode { opcode, offset: at } => DecodeError::UnknownOpcode {
+                opcode,
+                offset: offset + at,
+            },
+            DecodeError::Truncated { offset: at } => DecodeError::Truncated {
+                offset: offset + at,
+            },
+            DecodeError::VarintOverflow { offset: at } => DecodeError::VarintOverflow {
+                offset: offset + at,
+            },
+        }
+    }
+}
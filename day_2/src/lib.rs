@@ -0,0 +1,12 @@
+pub mod binary;
+pub mod command;
+pub mod error;
+pub mod navigator;
+pub mod navigator_3d;
+pub mod navigator_advanced;
+pub mod navigator_simple;
+pub mod script;
+mod solution;
+pub mod trace;
+
+pub use solution::Day2;
@@ -1,41 +1,11 @@
-use std::{
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-    str::FromStr,
-};
+use std::path::Path;
 
-mod command;
-mod navigator_advanced;
-mod navigator_simple;
+use aoc_common::{Args, MainError};
+use day_2::Day2;
 
-use crate::{
-    command::Command, navigator_advanced::NavigatorAdvanced, navigator_simple::NavigatorSimple,
-};
+// https://adventofcode.com/2021/day/2
+fn main() -> Result<(), MainError> {
+    let args = Args::from_env();
 
-fn main() -> Result<(), Box<dyn Error>> {
-    let mut navigator_simple = NavigatorSimple::new();
-    let mut navigator_advanced = NavigatorAdvanced::new();
-
-    let file = File::open("day_2/input.txt")?;
-    let reader = BufReader::new(file);
-
-    for line in reader.lines() {
-        let line = line?;
-        let command = Command::from_str(&line)?;
-        navigator_simple.navigate(&command);
-        navigator_advanced.navigate(&command);
-    }
-
-    println!(
-        "part 1: {}",
-        navigator_simple.get_horizontal() * navigator_simple.get_depth()
-    );
-
-    println!(
-        "part 2: {}",
-        navigator_advanced.get_horizontal() * navigator_advanced.get_depth()
-    );
-
-    Ok(())
+    aoc_common::run::<Day2>(&args, Path::new(env!("CARGO_MANIFEST_DIR")))
 }
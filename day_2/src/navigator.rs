@@ -0,0 +1,188 @@
+use serde::Serialize;
+
+use crate::{command::Command, error::NavigationError};
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize)]
+pub struct Position {
+    pub horizontal: i64,
+    pub depth: i64,
+}
+
+impl Position {
+    /// The puzzle answer, `None` when it overflows.
+    pub fn product(&self) -> Option<i64> {
+        self.horizontal.checked_mul(self.depth)
+    }
+}
+
+/// What happens when a command would take the submarine above the surface.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum Surfacing {
+    /// Stops at the surface
+    #[default]
+    Clamp,
+    /// Fails with [`NavigationError::AboveSurface`]
+    Error,
+    /// Keeps going, to a negative depth
+    AllowNegative,
+}
+
+impl Surfacing {
+    /// The depth the submarine ends up at when it heads to `depth`.
+    pub fn apply(self, depth: i64) -> Result<i64, NavigationError> {
+        match self {
+            _ if depth >= 0 => Ok(depth),
+            Surfacing::Clamp => Ok(0),
+            Surfacing::Error => Err(NavigationError::AboveSurface { depth }),
+            Surfacing::AllowNegative => Ok(depth),
+        }
+    }
+}
+
+/// A model of how the submarine moves in response to commands.
+pub trait Navigator {
+    /// Leaves the position unchanged when the command fails.
+    fn navigate(&mut self, command: &Command) -> Result<(), NavigationError>;
+
+    fn position(&self) -> Position;
+}
+
+/// Steers every navigator through the same course, one command at a time.
+///
+/// Returns the final position of each navigator, or the error it stopped at.
+/// A navigator that fails is given no further commands, the others carry on.
+pub fn navigate_all<'a>(
+    commands: impl IntoIterator<Item = &'a Command>,
+    navigators: &mut [&mut dyn Navigator],
+) -> Vec<Result<Position, NavigationError>> {
+    let mut errors: Vec<Option<NavigationError>> = navigators.iter().map(|_| None).collect();
+
+    for command in commands {
+        for (navigator, error) in navigators.iter_mut().zip(&mut errors) {
+            if error.is_none() {
+                *error = navigator.navigate(command).err();
+            }
+        }
+    }
+
+    navigators
+        .iter()
+        .zip(errors)
+        .map(|(navigator, error)| match error {
+            Some(error) => Err(error),
+            None => Ok(navigator.position()),
+        })
+        .collect()
+}
+
+/// Converts a command value to a signed step.
+pub(crate) fn step(value: u64) -> Result<i64, NavigationError> {
+    i64::try_from(value).map_err(|_| NavigationError::Overflow)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{navigator_advanced::NavigatorAdvanced, navigator_simple::NavigatorSimple};
+
+    /// Only ever moves forward.
+    #[derive(Default)]
+    struct NavigatorForward {
+        horizontal: i64,
+    }
+
+    impl Navigator for NavigatorForward {
+        fn navigate(&mut self, command: &Command) -> Result<(), NavigationError> {
+            if let Command::Forward(value) = command {
+                self.horizontal = self
+                    .horizontal
+                    .checked_add(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+            }
+
+            Ok(())
+        }
+
+        fn position(&self) -> Position {
+            Position {
+                horizontal: self.horizontal,
+                depth: 0,
+            }
+        }
+    }
+
+    #[test]
+    fn test_navigate_all() {
+        let commands: Vec<_> = [
+            "forward 5",
+            "down 5",
+            "forward 8",
+            "up 3",
+            "down 8",
+            "forward 2",
+        ]
+        .into_iter()
+        .map(|line| Command::from_str(line).unwrap())
+        .collect();
+
+        let mut simple = NavigatorSimple::new();
+        let mut advanced = NavigatorAdvanced::new();
+        let mut forward = NavigatorForward::default();
+
+        let positions = navigate_all(&commands, &mut [&mut simple, &mut advanced, &mut forward]);
+        let products: Vec<_> = positions
+            .iter()
+            .map(|position| position.as_ref().unwrap().product())
+            .collect();
+
+        assert_eq!(products, [Some(150), Some(900), Some(0)]);
+    }
+
+    #[test]
+    fn test_navigate_all_errors() {
+        let commands = [Command::Up(3), Command::Forward(2)];
+
+        let mut clamped = NavigatorSimple::new();
+        let mut strict = NavigatorSimple::with_surfacing(Surfacing::Error);
+
+        let positions = navigate_all(&commands, &mut [&mut clamped, &mut strict]);
+
+        assert_eq!(
+            positions,
+            [
+                Ok(Position {
+                    horizontal: 2,
+                    depth: 0
+                }),
+                Err(NavigationError::AboveSurface { depth: -3 }),
+            ]
+        );
+        assert_eq!(strict.position(), Position::default());
+    }
+
+    #[test]
+    fn test_surfacing() {
+        assert_eq!(Surfacing::Clamp.apply(-4), Ok(0));
+        assert_eq!(
+            Surfacing::Error.apply(-4),
+            Err(NavigationError::AboveSurface { depth: -4 })
+        );
+        assert_eq!(Surfacing::AllowNegative.apply(-4), Ok(-4));
+
+        for surfacing in [Surfacing::Clamp, Surfacing::Error, Surfacing::AllowNegative] {
+            assert_eq!(surfacing.apply(7), Ok(7));
+        }
+    }
+
+    #[test]
+    fn test_product_overflow() {
+        let position = Position {
+            horizontal: i64::MAX,
+            depth: 2,
+        };
+
+        assert_eq!(position.product(), None);
+    }
+}
@@ -0,0 +1,257 @@
+use serde::Serialize;
+
+use crate::{
+    command::Command,
+    error::NavigationError,
+    navigator::{step, Navigator, Position, Surfacing},
+};
+
+/// Positions at or beyond this distance do not fit the `i64` of a [`Position`].
+const LIMIT: f64 = i64::MAX as f64;
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize)]
+pub struct Position3d {
+    /// Distance along the starting heading
+    pub x: f64,
+    /// Distance to starboard of the starting heading
+    pub y: f64,
+    pub depth: i64,
+}
+
+/// Moves along its aim like [`NavigatorAdvanced`], in the direction of its heading.
+///
+/// The heading starts along `x`, `left` and `right` turn it by whole degrees.
+/// A course without turns ends up where [`NavigatorAdvanced`] does.
+///
+/// [`NavigatorAdvanced`]: crate::navigator_advanced::NavigatorAdvanced
+pub struct Navigator3d {
+    x: f64,
+    y: f64,
+    depth: i64,
+    aim: i64,
+    /// Degrees clockwise from `x`, seen from above
+    heading: u64,
+    surfacing: Surfacing,
+}
+
+impl Navigator3d {
+    pub fn new() -> Self {
+        Self::with_surfacing(Surfacing::default())
+    }
+
+    pub fn with_surfacing(surfacing: Surfacing) -> Self {
+        Self {
+            x: 0.0,
+            y: 0.0,
+            depth: 0,
+            aim: 0,
+            heading: 0,
+            surfacing,
+        }
+    }
+
+    /// In degrees, from 0 up to 360.
+    pub fn heading(&self) -> u64 {
+        self.heading
+    }
+
+    pub fn position_3d(&self) -> Position3d {
+        Position3d {
+            x: self.x,
+            y: self.y,
+            depth: self.depth,
+        }
+    }
+
+    /// Unit vector of the heading, exact along the four cardinal directions.
+    fn direction(&self) -> (f64, f64) {
+        match self.heading {
+            0 => (1.0, 0.0),
+            90 => (0.0, 1.0),
+            180 => (-1.0, 0.0),
+            270 => (0.0, -1.0),
+            heading => {
+                let (sin, cos) = (heading as f64).to_radians().sin_cos();
+                (cos, sin)
+            }
+        }
+    }
+
+    /// Moves `distance` along the heading, without changing depth.
+    fn advance(&self, distance: i64) -> Result<(f64, f64), NavigationError> {
+        let (dx, dy) = self.direction();
+
+        let x = self.x + dx * distance as f64;
+        let y = self.y + dy * distance as f64;
+
+        // also rejects NaN
+        if !(x.abs() < LIMIT && y.abs() < LIMIT) {
+            return Err(NavigationError::Overflow);
+        }
+
+        Ok((x, y))
+    }
+}
+
+impl Navigator for Navigator3d {
+    fn navigate(&mut self, command: &Command) -> Result<(), NavigationError> {
+        match command {
+            Command::Forward(value) | Command::Back(value) => {
+                let distance = match command {
+                    Command::Back(_) => -step(*value)?,
+                    _ => step(*value)?,
+                };
+
+                let (x, y) = self.advance(distance)?;
+                let depth = self
+                    .aim
+                    .checked_mul(distance)
+                    .and_then(|dive| self.depth.checked_add(dive))
+                    .ok_or(NavigationError::Overflow)?;
+
+                self.depth = self.surfacing.apply(depth)?;
+                (self.x, self.y) = (x, y);
+            }
+            Command::Hold(value) => (self.x, self.y) = self.advance(step(*value)?)?,
+            Command::Down(value) => {
+                self.aim = self
+                    .aim
+                    .checked_add(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+            }
+            Command::Up(value) => {
+                self.aim = self
+                    .aim
+                    .checked_sub(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+            }
+            Command::ResetAim => self.aim = 0,
+            Command::Surface => self.depth = 0,
+            Command::Left(degrees) => self.heading = (self.heading + 360 - degrees % 360) % 360,
+            Command::Right(degrees) => self.heading = (self.heading + degrees % 360) % 360,
+        }
+
+        Ok(())
+    }
+
+    /// The distance along the starting heading, rounded, and the depth.
+    fn position(&self) -> Position {
+        Position {
+            horizontal: self.x.round() as i64,
+            depth: self.depth,
+        }
+    }
+}
+
+impl Default for Navigator3d {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use proptest::prelude::*;
+
+    use super::*;
+    use crate::navigator_advanced::NavigatorAdvanced;
+
+    fn navigate(course: &str) -> Navigator3d {
+        let mut navigator = Navigator3d::new();
+
+        for line in course.lines() {
+            let command = Command::from_str(line).unwrap();
+            navigator.navigate(&command).unwrap();
+        }
+
+        navigator
+    }
+
+    /// Commands of the grammars before turns existed, small enough that floats stay exact.
+    fn planar_command() -> impl Strategy<Value = Command> {
+        prop_oneof![
+            (0..1000u64).prop_map(Command::Forward),
+            (0..1000u64).prop_map(Command::Down),
+            (0..1000u64).prop_map(Command::Up),
+            (0..1000u64).prop_map(Command::Back),
+            (0..1000u64).prop_map(Command::Hold),
+            Just(Command::ResetAim),
+            Just(Command::Surface),
+        ]
+    }
+
+    proptest! {
+        #[test]
+        fn test_matches_advanced(commands in prop::collection::vec(planar_command(), 0..64)) {
+            let mut navigator_3d = Navigator3d::new();
+            let mut navigator_advanced = NavigatorAdvanced::new();
+
+            for command in &commands {
+                prop_assert_eq!(
+                    navigator_3d.navigate(command),
+                    navigator_advanced.navigate(command)
+                );
+            }
+
+            prop_assert_eq!(navigator_3d.position(), navigator_advanced.position());
+            prop_assert_eq!(navigator_3d.position_3d().y, 0.0);
+        }
+    }
+
+    #[test]
+    fn test_square() {
+        let navigator = navigate(
+            "down 1\nforward 4\nright 90\nforward 4\nright 90\nforward 4\nright 450\nhold 4",
+        );
+
+        assert_eq!(
+            navigator.position_3d(),
+            Position3d {
+                x: 0.0,
+                y: 0.0,
+                depth: 12
+            }
+        );
+        assert_eq!(navigator.heading(), 270);
+    }
+
+    #[test]
+    fn test_left() {
+        let navigator = navigate("left 90\nforward 3\nleft 270\nback 2");
+
+        assert_eq!(navigator.position_3d().x, -2.0);
+        assert_eq!(navigator.position_3d().y, -3.0);
+        assert_eq!(navigator.heading(), 0);
+    }
+
+    #[test]
+    fn test_diagonal() {
+        let navigator = navigate("right 45\ndown 1\nforward 10");
+        let position = navigator.position_3d();
+
+        assert!((position.x - 50f64.sqrt()).abs() < 1e-9);
+        assert!((position.y - 50f64.sqrt()).abs() < 1e-9);
+        assert_eq!(position.depth, 10);
+        assert_eq!(navigator.position().horizontal, 7);
+    }
+
+    #[test]
+    fn test_errors() {
+        let mut navigator = Navigator3d::with_surfacing(Surfacing::Error);
+
+        navigator.navigate(&Command::Up(1)).unwrap();
+        assert_eq!(
+            navigator.navigate(&Command::Forward(2)),
+            Err(NavigationError::AboveSurface { depth: -2 })
+        );
+
+        navigator.navigate(&Command::ResetAim).unwrap();
+        navigator
+            .navigate(&Command::Hold(i64::MAX as u64))
+            .unwrap_err();
+
+        assert_eq!(navigator.position_3d(), Position3d::default());
+    }
+}
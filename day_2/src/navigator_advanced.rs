@@ -1,37 +1,95 @@
-use crate::command::Command;
+use crate::{
+    command::Command,
+    error::NavigationError,
+    navigator::{step, Navigator, Position, Surfacing},
+};
 
+/// Moves along its aim, which may point above the surface.
+/// The surfacing policy only applies to the depth.
+///
+/// `back` retraces a `forward` along the current aim, `hold` moves forward at the current depth,
+/// and `surface` rises straight to the surface, keeping the aim.
+/// It moves in a single plane, so turning has no effect.
 pub struct NavigatorAdvanced {
-    horizontal: u64,
-    depth: u64,
-    aim: u64,
+    horizontal: i64,
+    depth: i64,
+    aim: i64,
+    surfacing: Surfacing,
 }
 
 impl NavigatorAdvanced {
     pub fn new() -> Self {
+        Self::with_surfacing(Surfacing::default())
+    }
+
+    pub fn with_surfacing(surfacing: Surfacing) -> Self {
         Self {
             horizontal: 0,
             depth: 0,
             aim: 0,
+            surfacing,
         }
     }
+}
 
-    pub fn navigate(&mut self, command: &Command) {
+impl Navigator for NavigatorAdvanced {
+    fn navigate(&mut self, command: &Command) -> Result<(), NavigationError> {
         match command {
-            Command::Forward(value) => {
-                self.horizontal += value;
-                self.depth += self.aim * value;
+            Command::Forward(value) | Command::Back(value) => {
+                let value = match command {
+                    Command::Back(_) => -step(*value)?,
+                    _ => step(*value)?,
+                };
+                let horizontal = self.horizontal.checked_add(value);
+                let depth = self
+                    .aim
+                    .checked_mul(value)
+                    .and_then(|dive| self.depth.checked_add(dive));
+
+                let (Some(horizontal), Some(depth)) = (horizontal, depth) else {
+                    return Err(NavigationError::Overflow);
+                };
+
+                self.depth = self.surfacing.apply(depth)?;
+                self.horizontal = horizontal;
+            }
+            Command::Down(value) => {
+                self.aim = self
+                    .aim
+                    .checked_add(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+            }
+            Command::Up(value) => {
+                self.aim = self
+                    .aim
+                    .checked_sub(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
             }
-            Command::Down(value) => self.aim += value,
-            Command::Up(value) => self.aim -= value,
+            Command::Hold(value) => {
+                self.horizontal = self
+                    .horizontal
+                    .checked_add(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+            }
+            Command::ResetAim => self.aim = 0,
+            Command::Left(_) | Command::Right(_) => {}
+            Command::Surface => self.depth = 0,
         }
+
+        Ok(())
     }
 
-    pub fn get_horizontal(&self) -> u64 {
-        self.horizontal
+    fn position(&self) -> Position {
+        Position {
+            horizontal: self.horizontal,
+            depth: self.depth,
+        }
     }
+}
 
-    pub fn get_depth(&self) -> u64 {
-        self.depth
+impl Default for NavigatorAdvanced {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -56,10 +114,10 @@ mod tests {
 
         for input in INPUT {
             let command = Command::from_str(input).unwrap();
-            navigator.navigate(&command);
+            navigator.navigate(&command).unwrap();
         }
 
-        assert_eq!(navigator.get_horizontal(), 15);
+        assert_eq!(navigator.position().horizontal, 15);
     }
 
     #[test]
@@ -68,9 +126,111 @@ mod tests {
 
         for input in INPUT {
             let command = Command::from_str(input).unwrap();
-            navigator.navigate(&command);
+            navigator.navigate(&command).unwrap();
+        }
+
+        assert_eq!(navigator.position().depth, 60);
+    }
+
+    #[test]
+    fn test_aim_above_zero() {
+        let mut navigator = NavigatorAdvanced::new();
+
+        navigator.navigate(&Command::Up(5)).unwrap();
+        navigator.navigate(&Command::Forward(2)).unwrap();
+
+        assert_eq!(navigator.position().depth, 0);
+    }
+
+    #[test]
+    fn test_surfacing_policies() {
+        let commands = [Command::Down(1), Command::Forward(4), Command::Up(3)];
+
+        let mut navigator = NavigatorAdvanced::with_surfacing(Surfacing::AllowNegative);
+        for command in commands.iter().chain([&Command::Forward(3)]) {
+            navigator.navigate(command).unwrap();
+        }
+
+        assert_eq!(navigator.position().depth, -2);
+
+        let mut navigator = NavigatorAdvanced::with_surfacing(Surfacing::Error);
+        for command in &commands {
+            navigator.navigate(command).unwrap();
         }
 
-        assert_eq!(navigator.get_depth(), 60);
+        assert_eq!(
+            navigator.navigate(&Command::Forward(3)),
+            Err(NavigationError::AboveSurface { depth: -2 })
+        );
+        assert_eq!(
+            navigator.position(),
+            Position {
+                horizontal: 4,
+                depth: 4
+            }
+        );
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut navigator = NavigatorAdvanced::new();
+
+        navigator.navigate(&Command::Down(1 << 40)).unwrap();
+
+        assert_eq!(
+            navigator.navigate(&Command::Forward(1 << 40)),
+            Err(NavigationError::Overflow)
+        );
+        assert_eq!(navigator.position(), Position::default());
+    }
+
+    #[test]
+    fn test_extended_commands() {
+        let mut navigator = NavigatorAdvanced::new();
+
+        for command in [
+            Command::Down(2),
+            Command::Forward(5),
+            Command::Hold(3),
+            Command::Back(1),
+        ] {
+            navigator.navigate(&command).unwrap();
+        }
+
+        assert_eq!(
+            navigator.position(),
+            Position {
+                horizontal: 7,
+                depth: 8
+            }
+        );
+
+        navigator.navigate(&Command::Surface).unwrap();
+        navigator.navigate(&Command::Forward(1)).unwrap();
+
+        assert_eq!(navigator.position().depth, 2);
+
+        navigator.navigate(&Command::ResetAim).unwrap();
+        navigator.navigate(&Command::Forward(4)).unwrap();
+
+        assert_eq!(
+            navigator.position(),
+            Position {
+                horizontal: 12,
+                depth: 2
+            }
+        );
+    }
+
+    #[test]
+    fn test_back_above_surface() {
+        let mut navigator = NavigatorAdvanced::with_surfacing(Surfacing::Error);
+
+        navigator.navigate(&Command::Down(1)).unwrap();
+
+        assert_eq!(
+            navigator.navigate(&Command::Back(1)),
+            Err(NavigationError::AboveSurface { depth: -1 })
+        );
     }
 }
@@ -1,32 +1,78 @@
-use crate::command::Command;
+use crate::{
+    command::Command,
+    error::NavigationError,
+    navigator::{step, Navigator, Position, Surfacing},
+};
 
+/// Moves straight up and down, so it has no aim to hold or reset,
+/// and along a single line, so turning has no effect.
 pub struct NavigatorSimple {
-    horizontal: u64,
-    depth: u64,
+    horizontal: i64,
+    depth: i64,
+    surfacing: Surfacing,
 }
 
 impl NavigatorSimple {
     pub fn new() -> Self {
+        Self::with_surfacing(Surfacing::default())
+    }
+
+    pub fn with_surfacing(surfacing: Surfacing) -> Self {
         Self {
             horizontal: 0,
             depth: 0,
+            surfacing,
         }
     }
+}
 
-    pub fn navigate(&mut self, command: &Command) {
+impl Navigator for NavigatorSimple {
+    fn navigate(&mut self, command: &Command) -> Result<(), NavigationError> {
         match command {
-            Command::Forward(value) => self.horizontal += value,
-            Command::Down(value) => self.depth += value,
-            Command::Up(value) => self.depth -= value,
+            Command::Forward(value) | Command::Hold(value) => {
+                self.horizontal = self
+                    .horizontal
+                    .checked_add(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+            }
+            Command::Back(value) => {
+                self.horizontal = self
+                    .horizontal
+                    .checked_sub(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+            }
+            Command::Down(value) => {
+                let depth = self
+                    .depth
+                    .checked_add(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+                self.depth = self.surfacing.apply(depth)?;
+            }
+            Command::Up(value) => {
+                let depth = self
+                    .depth
+                    .checked_sub(step(*value)?)
+                    .ok_or(NavigationError::Overflow)?;
+                self.depth = self.surfacing.apply(depth)?;
+            }
+            Command::ResetAim | Command::Left(_) | Command::Right(_) => {}
+            Command::Surface => self.depth = 0,
         }
+
+        Ok(())
     }
 
-    pub fn get_horizontal(&self) -> u64 {
-        self.horizontal
+    fn position(&self) -> Position {
+        Position {
+            horizontal: self.horizontal,
+            depth: self.depth,
+        }
     }
+}
 
-    pub fn get_depth(&self) -> u64 {
-        self.depth
+impl Default for NavigatorSimple {
+    fn default() -> Self {
+        Self::new()
     }
 }
 
@@ -51,10 +97,10 @@ mod tests {
 
         for input in INPUT {
             let command = Command::from_str(input).unwrap();
-            navigator.navigate(&command);
+            navigator.navigate(&command).unwrap();
         }
 
-        assert_eq!(navigator.get_horizontal(), 15);
+        assert_eq!(navigator.position().horizontal, 15);
     }
 
     #[test]
@@ -63,9 +109,81 @@ mod tests {
 
         for input in INPUT {
             let command = Command::from_str(input).unwrap();
-            navigator.navigate(&command);
+            navigator.navigate(&command).unwrap();
         }
 
-        assert_eq!(navigator.get_depth(), 10);
+        assert_eq!(navigator.position().depth, 10);
+    }
+
+    #[test]
+    fn test_surface_above_zero() {
+        let mut navigator = NavigatorSimple::new();
+
+        navigator.navigate(&Command::Down(2)).unwrap();
+        navigator.navigate(&Command::Up(5)).unwrap();
+
+        assert_eq!(navigator.position().depth, 0);
+    }
+
+    #[test]
+    fn test_surfacing_policies() {
+        let mut navigator = NavigatorSimple::with_surfacing(Surfacing::AllowNegative);
+        navigator.navigate(&Command::Up(5)).unwrap();
+
+        assert_eq!(navigator.position().depth, -5);
+
+        let mut navigator = NavigatorSimple::with_surfacing(Surfacing::Error);
+        navigator.navigate(&Command::Down(2)).unwrap();
+
+        assert_eq!(
+            navigator.navigate(&Command::Up(5)),
+            Err(NavigationError::AboveSurface { depth: -3 })
+        );
+        assert_eq!(navigator.position().depth, 2);
+    }
+
+    #[test]
+    fn test_overflow() {
+        let mut navigator = NavigatorSimple::new();
+
+        navigator
+            .navigate(&Command::Forward(i64::MAX as u64))
+            .unwrap();
+
+        assert_eq!(
+            navigator.navigate(&Command::Forward(1)),
+            Err(NavigationError::Overflow)
+        );
+        assert_eq!(
+            navigator.navigate(&Command::Down(u64::MAX)),
+            Err(NavigationError::Overflow)
+        );
+        assert_eq!(navigator.position().horizontal, i64::MAX);
+    }
+
+    #[test]
+    fn test_extended_commands() {
+        let mut navigator = NavigatorSimple::new();
+
+        for command in [
+            Command::Down(4),
+            Command::Hold(5),
+            Command::ResetAim,
+            Command::Back(7),
+        ] {
+            navigator.navigate(&command).unwrap();
+        }
+
+        assert_eq!(
+            navigator.position(),
+            Position {
+                horizontal: -2,
+                depth: 4
+            }
+        );
+
+        navigator.navigate(&Command::Surface).unwrap();
+
+        assert_eq!(navigator.position().depth, 0);
     }
 }
@@ -0,0 +1,472 @@
+//! A small scripting language that compiles into a stream of [`Command`]s.
+//!
+//! ```text
+//! # comments run to the end of the line
+//! let depth = 2
+//! macro dive { down depth; forward 5 }
+//!
+//! repeat 10 {
+//!     dive
+//!     hold 3
+//! }
+//! surface
+//! ```
+//!
+//! Statements are separated by newlines or `;`. Every command of the latest [`Grammar`] is available,
+//! and a value is either an integer or a variable. Variables and macros have to be defined before
+//! they are used, and a macro sees the variables as they were where it is defined.
+//!
+//! [`Grammar`]: crate::command::Grammar
+
+use std::{collections::HashMap, rc::Rc};
+
+use aoc_common::parse::{self, ParseError, ParseErrorKind};
+
+use crate::{
+    command::Command,
+    error::NavigationError,
+    navigator::{navigate_all, Navigator, Position},
+};
+
+/// Words that cannot name a variable or a macro.
+const RESERVED: [&str; 11] = [
+    "let", "macro", "repeat", "forward", "down", "up", "back", "hold", "surface", "left", "right",
+];
+
+/// Blocks are compiled recursively, so their nesting is bounded to keep the stack in check.
+const MAX_NESTING: usize = 64;
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Node {
+    Command(Command),
+    /// Macro calls share their body, so nested calls cannot blow up the script.
+    Repeat(u64, Rc<[Node]>),
+}
+
+/// A compiled script, its loops are only unrolled as the commands are iterated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Script {
+    nodes: Vec<Node>,
+}
+
+impl Script {
+    /// Fails with the script line of the first error.
+    pub fn compile(source: &str) -> Result<Self, ParseError> {
+        let mut compiler = Compiler {
+            tokens: tokenize(source).peekable(),
+            last_line: source.lines().enumerate().last().unwrap_or((0, "")),
+            line: 0,
+            variables: HashMap::new(),
+            macros: HashMap::new(),
+            nesting: 0,
+        };
+
+        let nodes = compiler.block(None)?;
+
+        Ok(Self { nodes })
+    }
+
+    pub fn commands(&self) -> Commands<'_> {
+        Commands {
+            stack: vec![Frame {
+                nodes: &self.nodes,
+                index: 0,
+                remaining: 1,
+            }],
+        }
+    }
+
+    /// Steers every navigator through the script, see [`navigate_all`].
+    pub fn navigate(
+        &self,
+        navigators: &mut [&mut dyn Navigator],
+    ) -> Vec<Result<Position, NavigationError>> {
+        navigate_all(self.commands(), navigators)
+    }
+}
+
+pub struct Commands<'a> {
+    stack: Vec<Frame<'a>>,
+}
+
+struct Frame<'a> {
+    nodes: &'a [Node],
+    index: usize,
+    remaining: u64,
+}
+
+impl<'a> Iterator for Commands<'a> {
+    type Item = &'a Command;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let frame = self.stack.last_mut()?;
+
+            let Some(node) = frame.nodes.get(frame.index) else {
+                frame.remaining -= 1;
+                frame.index = 0;
+
+                if frame.remaining == 0 {
+                    self.stack.pop();
+                }
+
+                continue;
+            };
+
+            frame.index += 1;
+
+            match node {
+                Node::Command(command) => return Some(command),
+                Node::Repeat(count, nodes) => self.stack.push(Frame {
+                    nodes,
+                    index: 0,
+                    remaining: *count,
+                }),
+            }
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy)]
+struct Token<'a> {
+    text: &'a str,
+    /// The whole line the token was sliced from
+    line: &'a str,
+    index: usize,
+}
+
+impl Token<'_> {
+    fn error(&self, kind: ParseErrorKind) -> ParseError {
+        ParseError::new(self.line, self.text, kind).at_line(self.index + 1)
+    }
+
+    fn unexpected(&self) -> ParseError {
+        self.error(ParseErrorKind::UnexpectedField {
+            value: self.text.to_string(),
+        })
+    }
+}
+
+fn tokenize(source: &str) -> impl Iterator<Item = Token<'_>> {
+    source.lines().enumerate().flat_map(|(index, line)| {
+        let code = line.split('#').next().unwrap_or_default();
+        let mut rest = code;
+
+        std::iter::from_fn(move || {
+            let start = rest.len() - rest.trim_start().len();
+            rest = &rest[start..];
+
+            let length = match rest.chars().next()? {
+                '{' | '}' | ';' | '=' => 1,
+                _ => rest
+                    .find(|c: char| c.is_whitespace() || "{};=".contains(c))
+                    .unwrap_or(rest.len()),
+            };
+
+            let (text, tail) = rest.split_at(length);
+            rest = tail;
+
+            Some(Token { text, line, index })
+        })
+    })
+}
+
+struct Compiler<'a, I: Iterator<Item = Token<'a>>> {
+    tokens: std::iter::Peekable<I>,
+    last_line: (usize, &'a str),
+    /// Line of the last token taken
+    line: usize,
+    variables: HashMap<&'a str, u64>,
+    macros: HashMap<&'a str, Rc<[Node]>>,
+    nesting: usize,
+}
+
+impl<'a, I: Iterator<Item = Token<'a>>> Compiler<'a, I> {
+    /// Statements up to the `}` that closes `open`, or up to the end of the script.
+    fn block(&mut self, open: Option<Token<'a>>) -> Result<Vec<Node>, ParseError> {
+        let mut nodes = Vec::new();
+
+        loop {
+            let Some(token) = self.take() else {
+                return match open {
+                    Some(_) => Err(self.missing("`}`")),
+                    None => Ok(nodes),
+                };
+            };
+
+            match token.text {
+                ";" => continue,
+                "}" if open.is_some() => return Ok(nodes),
+                "let" => {
+                    let name = self.name()?;
+                    self.expect("=")?;
+                    let value = self.value()?;
+
+                    self.variables.insert(name, value);
+                }
+                "macro" => {
+                    let name = self.name()?;
+                    let body = self.nested_block()?;
+
+                    self.macros.insert(name, body.into());
+                }
+                "repeat" => {
+                    let count = self.value()?;
+                    let body = self.nested_block()?;
+
+                    // loops that cannot produce a command are dropped, so iterating never stalls
+                    if count > 0 && !body.is_empty() {
+                        nodes.push(Node::Repeat(count, body.into()));
+                    }
+                }
+                "forward" | "down" | "up" | "back" | "hold" | "left" | "right" => {
+                    let value = self.value()?;
+
+                    nodes.push(Node::Command(match token.text {
+                        "forward" => Command::Forward(value),
+                        "down" => Command::Down(value),
+                        "up" => Command::Up(value),
+                        "back" => Command::Back(value),
+                        "hold" => Command::Hold(value),
+                        "left" => Command::Left(value),
+                        _ => Command::Right(value),
+                    }));
+                }
+                "reset-aim" => nodes.push(Node::Command(Command::ResetAim)),
+                "surface" => nodes.push(Node::Command(Command::Surface)),
+                name => match self.macros.get(name) {
+                    Some(body) if body.is_empty() => {}
+                    Some(body) => nodes.push(Node::Repeat(1, body.clone())),
+                    None => {
+                        return Err(token.error(ParseErrorKind::InvalidValue {
+                            name: "statement",
+                            value: name.to_string(),
+                        }))
+                    }
+                },
+            }
+
+            self.end_of_statement()?;
+        }
+    }
+
+    /// A `{` and the block it opens.
+    fn nested_block(&mut self) -> Result<Vec<Node>, ParseError> {
+        let open = self.expect("{")?;
+
+        if self.nesting == MAX_NESTING {
+            return Err(open.error(ParseErrorKind::Unsupported {
+                value: open.text.to_string(),
+                reason: format!("blocks cannot nest more than {MAX_NESTING} levels deep"),
+            }));
+        }
+
+        self.nesting += 1;
+        let body = self.block(Some(open));
+        self.nesting -= 1;
+
+        body
+    }
+
+    fn take(&mut self) -> Option<Token<'a>> {
+        let token = self.tokens.next()?;
+        self.line = token.index;
+
+        Some(token)
+    }
+
+    /// A statement ends with the line, a `;` or the `}` of its block.
+    fn end_of_statement(&mut self) -> Result<(), ParseError> {
+        match self.tokens.peek() {
+            Some(next) if next.index == self.line && !matches!(next.text, ";" | "}") => {
+                Err(next.unexpected())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    fn next(&mut self, name: &'static str) -> Result<Token<'a>, ParseError> {
+        self.take().ok_or_else(|| self.missing(name))
+    }
+
+    fn expect(&mut self, text: &'static str) -> Result<Token<'a>, ParseError> {
+        let token = self.next(text)?;
+
+        match token.text == text {
+            true => Ok(token),
+            false => Err(token.unexpected()),
+        }
+    }
+
+    /// The name of a new variable or macro.
+    fn name(&mut self) -> Result<&'a str, ParseError> {
+        let token = self.next("name")?;
+
+        let is_identifier = token
+            .text
+            .starts_with(|c: char| c.is_alphabetic() || c == '_')
+            && token.text.chars().all(|c| c.is_alphanumeric() || c == '_');
+
+        if !is_identifier || RESERVED.contains(&token.text) {
+            return Err(token.error(ParseErrorKind::InvalidValue {
+                name: "name",
+                value: token.text.to_string(),
+            }));
+        }
+
+        Ok(token.text)
+    }
+
+    /// An integer or a variable.
+    fn value(&mut self) -> Result<u64, ParseError> {
+        let token = self.next("value")?;
+
+        if token.text.starts_with(|c: char| c.is_ascii_digit()) {
+            return parse::integer_in(token.line, token.text)
+                .map_err(|e| e.at_line(token.index + 1));
+        }
+
+        self.variables.get(token.text).copied().ok_or_else(|| {
+            token.error(ParseErrorKind::InvalidValue {
+                name: "variable",
+                value: token.text.to_string(),
+            })
+        })
+    }
+
+    /// Points past the end of the script.
+    fn missing(&self, name: &'static str) -> ParseError {
+        let (index, line) = self.last_line;
+        let end = &line[line.len()..];
+
+        ParseError::new(line, end, ParseErrorKind::MissingField { name }).at_line(index + 1)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{navigator_advanced::NavigatorAdvanced, navigator_simple::NavigatorSimple};
+
+    fn commands(source: &str) -> Vec<Command> {
+        let script = Script::compile(source).unwrap();
+
+        script.commands().cloned().collect()
+    }
+
+    fn course(lines: &[&str]) -> Vec<Command> {
+        lines
+            .iter()
+            .map(|line| Command::from_str(line).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn test_repeat() {
+        assert_eq!(
+            commands("repeat 2 { forward 5; down 2 }"),
+            course(&["forward 5", "down 2", "forward 5", "down 2"])
+        );
+        assert_eq!(
+            commands("repeat 2 {\n  up 1\n  repeat 3 { hold 4 }\n}\nsurface"),
+            course(&[
+                "up 1", "hold 4", "hold 4", "hold 4", "up 1", "hold 4", "hold 4", "hold 4",
+                "surface"
+            ])
+        );
+        assert!(commands("repeat 0 { forward 1 }").is_empty());
+        assert!(commands("repeat 18446744073709551615 { repeat 0 { forward 1 } }").is_empty());
+
+        let source =
+            "macro a { forward 1 }\nmacro b { a; a }\nmacro c { b; b }\nmacro d { c; c }\nd";
+        assert_eq!(commands(source).len(), 8);
+    }
+
+    #[test]
+    fn test_variables_and_macros() {
+        let source = "
+            # a square dive
+            let step = 3
+            macro dive { down step; forward step }
+            let step = 1
+            dive; back step
+            dive
+            reset-aim
+        ";
+
+        assert_eq!(
+            commands(source),
+            course(&[
+                "down 3",
+                "forward 3",
+                "back 1",
+                "down 3",
+                "forward 3",
+                "reset-aim"
+            ])
+        );
+    }
+
+    #[test]
+    fn test_navigate() {
+        let source = "let distance = 5\nrepeat 2 { forward distance; down 2 }\nup 1; forward 3";
+        let course = "forward 5\ndown 2\nforward 5\ndown 2\nup 1\nforward 3";
+
+        let script = Script::compile(source).unwrap();
+        let expected: Vec<_> = course
+            .lines()
+            .map(|line| Command::from_str(line).unwrap())
+            .collect();
+
+        assert!(script.commands().eq(&expected));
+
+        let mut simple = NavigatorSimple::new();
+        let mut advanced = NavigatorAdvanced::new();
+        let positions = script.navigate(&mut [&mut simple, &mut advanced]);
+
+        let mut simple_course = NavigatorSimple::new();
+        let mut advanced_course = NavigatorAdvanced::new();
+        let course_positions =
+            navigate_all(&expected, &mut [&mut simple_course, &mut advanced_course]);
+
+        assert_eq!(positions, course_positions);
+        assert_eq!(positions[1].as_ref().unwrap().product(), Some(13 * 19));
+    }
+
+    #[test]
+    fn test_errors() {
+        let error = |source: &str| Script::compile(source).unwrap_err();
+
+        let e = error("forward 5\nrepeat 2 {\n  down depth\n}");
+        assert_eq!((e.line, e.column), (3, 8));
+        assert_eq!(e.kind.to_string(), "invalid variable \"depth\"");
+
+        let e = error("repeat 2 {\n  forward 1\n");
+        assert_eq!((e.line, e.column), (2, 12));
+        assert_eq!(e.kind.to_string(), "`}` not found");
+
+        let e = error("forward 5 down 2");
+        assert_eq!((e.line, e.column), (1, 11));
+
+        let e = error("repeat 2 {\n  up 1\n} forward 3");
+        assert_eq!((e.line, e.column), (3, 3));
+
+        let e = error("let forward = 2");
+        assert_eq!((e.line, e.column), (1, 5));
+
+        let e = error("\n\nsideways 3");
+        assert_eq!((e.line, e.column), (3, 1));
+        assert_eq!(e.kind.to_string(), "invalid statement \"sideways\"");
+
+        let e = error("up x1\nup 99999999999999999999");
+        assert_eq!(e.line, 1);
+        let e = error("up 1\nup 99999999999999999999");
+        assert_eq!((e.line, e.column), (2, 4));
+        assert!(Script::compile("}").is_err());
+
+        let e = error(&"repeat 2 {".repeat(MAX_NESTING + 1));
+        assert_eq!(e.column, MAX_NESTING * 10 + 10);
+    }
+}
@@ -0,0 +1,71 @@
+use std::error::Error;
+
+use aoc_common::Solution;
+
+use crate::{
+    command,
+    error::{CourseError, NavigationError},
+    navigator::{Navigator, Position},
+    navigator_advanced::NavigatorAdvanced,
+    navigator_simple::NavigatorSimple,
+};
+
+// https://adventofcode.com/2021/day/2
+pub struct Day2;
+
+impl Solution for Day2 {
+    const DAY: u8 = 2;
+
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn part_1(input: &str) -> Result<Self::Part1, Box<dyn Error>> {
+        let position = navigate(input, NavigatorSimple::new())?;
+
+        Ok(position.product().ok_or(NavigationError::Overflow)?)
+    }
+
+    fn part_2(input: &str) -> Result<Self::Part2, Box<dyn Error>> {
+        let position = navigate(input, NavigatorAdvanced::new())?;
+
+        Ok(position.product().ok_or(NavigationError::Overflow)?)
+    }
+}
+
+fn navigate(input: &str, mut navigator: impl Navigator) -> Result<Position, CourseError> {
+    for command in command::parse_course(input) {
+        let (line, command) = command?;
+
+        navigator
+            .navigate(&command)
+            .map_err(|error| CourseError::Navigation { line, error })?;
+    }
+
+    Ok(navigator.position())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "forward 5\ndown 5\nforward 8\nup 3\ndown 8\nforward 2\n";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day2::part_1(INPUT).unwrap(), 150);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day2::part_2(INPUT).unwrap(), 900);
+    }
+
+    #[test]
+    fn test_grammar_2() {
+        let input = format!("grammar 2\n{INPUT}back 3\nhold 4\n");
+
+        assert_eq!(Day2::part_1(&input).unwrap(), 160);
+        assert_eq!(Day2::part_2(&input).unwrap(), 480);
+        assert!(Day2::part_1(&format!("{INPUT}surface\n")).is_err());
+    }
+}
@@ -0,0 +1,232 @@
+use std::fmt::Write;
+
+use serde::Serialize;
+
+use crate::{
+    command::Command,
+    error::NavigationError,
+    navigator::{Navigator, Position},
+};
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Step {
+    pub command: Command,
+    /// Position right after the command
+    pub position: Position,
+}
+
+/// Every command a navigator followed, and where each one took it.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Trace {
+    start: Position,
+    steps: Vec<Step>,
+}
+
+impl Trace {
+    pub fn steps(&self) -> &[Step] {
+        &self.steps
+    }
+
+    /// Position after the first `step` commands, step 0 is the start.
+    pub fn seek(&self, step: usize) -> Option<Position> {
+        match step {
+            0 => Some(self.start),
+            _ => self.steps.get(step - 1).map(|step| step.position),
+        }
+    }
+
+    /// Runs the first `step` commands again through another navigator,
+    /// e.g. to find the first step where two models disagree.
+    pub fn replay(
+        &self,
+        navigator: &mut dyn Navigator,
+        step: usize,
+    ) -> Result<Position, NavigationError> {
+        for step in self.steps.iter().take(step) {
+            navigator.navigate(&step.command)?;
+        }
+
+        Ok(navigator.position())
+    }
+
+    /// One row per step, numbered from 1.
+    pub fn to_csv(&self) -> String {
+        let mut csv = String::from("step,command,horizontal,depth\n");
+
+        for (index, step) in self.steps.iter().enumerate() {
+            let Position { horizontal, depth } = step.position;
+
+            // writing to a `String` cannot fail
+            let _ = writeln!(csv, "{},{},{horizontal},{depth}", index + 1, step.command);
+        }
+
+        csv
+    }
+
+    /// An array of steps, numbered from 1.
+    pub fn to_json(&self) -> String {
+        #[derive(Serialize)]
+        struct Record {
+            step: usize,
+            command: String,
+            #[serde(flatten)]
+            position: Position,
+        }
+
+        let records: Vec<_> = self
+            .steps
+            .iter()
+            .enumerate()
+            .map(|(index, step)| Record {
+                step: index + 1,
+                command: step.command.to_string(),
+                position: step.position,
+            })
+            .collect();
+
+        serde_json::to_string(&records).expect("a trace is always valid JSON")
+    }
+}
+
+/// Records a [`Trace`] of the navigator it wraps.
+///
+/// Commands that fail leave the position unchanged, so they are not recorded.
+pub struct Traced<N> {
+    navigator: N,
+    trace: Trace,
+}
+
+impl<N: Navigator> Traced<N> {
+    pub fn new(navigator: N) -> Self {
+        let trace = Trace {
+            start: navigator.position(),
+            steps: Vec::new(),
+        };
+
+        Self { navigator, trace }
+    }
+
+    pub fn trace(&self) -> &Trace {
+        &self.trace
+    }
+
+    pub fn into_trace(self) -> Trace {
+        self.trace
+    }
+}
+
+impl<N: Navigator> Navigator for Traced<N> {
+    fn navigate(&mut self, command: &Command) -> Result<(), NavigationError> {
+        self.navigator.navigate(command)?;
+
+        self.trace.steps.push(Step {
+            command: command.clone(),
+            position: self.navigator.position(),
+        });
+
+        Ok(())
+    }
+
+    fn position(&self) -> Position {
+        self.navigator.position()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::str::FromStr;
+
+    use super::*;
+    use crate::{
+        navigator::Surfacing, navigator_advanced::NavigatorAdvanced,
+        navigator_simple::NavigatorSimple,
+    };
+
+    const INPUT: [&str; 6] = [
+        "forward 5",
+        "down 5",
+        "forward 8",
+        "up 3",
+        "down 8",
+        "forward 2",
+    ];
+
+    fn trace() -> Trace {
+        let mut navigator = Traced::new(NavigatorAdvanced::new());
+
+        for input in INPUT {
+            let command = Command::from_str(input).unwrap();
+            navigator.navigate(&command).unwrap();
+        }
+
+        assert_eq!(navigator.position().product(), Some(900));
+        navigator.into_trace()
+    }
+
+    #[test]
+    fn test_seek() {
+        let trace = trace();
+
+        assert_eq!(trace.steps().len(), 6);
+        assert_eq!(trace.seek(0), Some(Position::default()));
+        assert_eq!(
+            trace.seek(3),
+            Some(Position {
+                horizontal: 13,
+                depth: 40
+            })
+        );
+        assert_eq!(trace.seek(6).unwrap().depth, 60);
+        assert_eq!(trace.seek(7), None);
+    }
+
+    #[test]
+    fn test_replay() {
+        let trace = trace();
+
+        let mut navigator = NavigatorSimple::new();
+        assert_eq!(
+            trace.replay(&mut navigator, 3),
+            Ok(Position {
+                horizontal: 13,
+                depth: 5
+            })
+        );
+
+        let mut navigator = NavigatorAdvanced::new();
+        assert_eq!(
+            trace.replay(&mut navigator, 100),
+            Ok(trace.seek(6).unwrap())
+        );
+    }
+
+    #[test]
+    fn test_failed_commands() {
+        let mut navigator = Traced::new(NavigatorSimple::with_surfacing(Surfacing::Error));
+
+        navigator.navigate(&Command::Down(1)).unwrap();
+        navigator.navigate(&Command::Up(2)).unwrap_err();
+
+        assert_eq!(navigator.trace().steps().len(), 1);
+    }
+
+    #[test]
+    fn test_export() {
+        let trace = trace();
+
+        let csv = trace.to_csv();
+        let mut rows = csv.lines();
+
+        assert_eq!(rows.next(), Some("step,command,horizontal,depth"));
+        assert_eq!(rows.next(), Some("1,forward 5,5,0"));
+        assert_eq!(rows.last(), Some("6,forward 2,15,60"));
+
+        let json: serde_json::Value = serde_json::from_str(&trace.to_json()).unwrap();
+
+        assert_eq!(json.as_array().unwrap().len(), 6);
+        assert_eq!(
+            json[2],
+            serde_json::json!({ "step": 3, "command": "forward 8", "horizontal": 13, "depth": 40 })
+        );
+    }
+}
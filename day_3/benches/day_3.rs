@@ -0,0 +1,37 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_3::parser::Parser;
+
+const EXAMPLE: &str =
+    "00100\n11110\n10110\n10111\n10101\n01111\n00111\n11100\n10000\n11001\n00010\n01010\n";
+const INPUT: &str = include_str!("../input.txt");
+
+fn build_parser<const N: usize>(input: &str) -> Parser<N> {
+    let mut parser = Parser::<N>::new();
+
+    for line in input.lines() {
+        parser.parse_line(line).unwrap();
+    }
+
+    parser
+}
+
+fn oxygen_generator_rating(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Parser::oxygen_generator_rating");
+
+    let parser = build_parser::<5>(EXAMPLE);
+    group.bench_function("example", |b| {
+        b.iter(|| black_box(&parser).oxygen_generator_rating().unwrap())
+    });
+
+    let parser = build_parser::<12>(INPUT);
+    group.bench_function("input", |b| {
+        b.iter(|| black_box(&parser).oxygen_generator_rating().unwrap())
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, oxygen_generator_rating);
+criterion_main!(benches);
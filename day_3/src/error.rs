@@ -0,0 +1,11 @@
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum DiagnosticError {
+    #[error("no records")]
+    NoRecords,
+    #[error("no record left after filtering on bit {index}")]
+    NoMatchingRecord { index: usize },
+    #[error("{count} identical records left after filtering on every bit")]
+    AmbiguousRecords { count: usize },
+}
@@ -0,0 +1,5 @@
+pub mod error;
+pub mod parser;
+mod solution;
+
+pub use solution::Day3;
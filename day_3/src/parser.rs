@@ -1,6 +1,14 @@
 use std::cmp::Ordering;
 
-use anyhow::Context;
+use aoc_common::parse::{self, ParseError};
+
+use crate::error::DiagnosticError;
+
+#[derive(Debug, Clone, Copy)]
+enum BitCriteria {
+    MostCommon,
+    LeastCommon,
+}
 
 /// Using const generics and arrays mostly for the learning opportunity.
 pub struct Parser<const N: usize> {
@@ -18,26 +26,8 @@ impl<const N: usize> Parser<N> {
         }
     }
 
-    pub fn parse_line(&mut self, input: &str) -> Result<(), anyhow::Error> {
-        let allowed = [0, 1];
-
-        let digits = input
-            .chars()
-            // parse from char to number
-            .filter_map(|ch| ch.to_digit(10))
-            // validate that the digit is in the allowed range
-            .filter(|digit| allowed.contains(digit))
-            .collect::<Vec<u32>>();
-
-        // validate the length of the input
-        if digits.len() != N {
-            return Err(anyhow::anyhow!("invalid input length or content"));
-        }
-
-        let digits: [u32; N] = digits
-            .try_into()
-            .ok()
-            .context("failed to convert to an array")?;
+    pub fn parse_line(&mut self, input: &str) -> Result<(), ParseError> {
+        let digits = parse::binary_digits::<N>(input)?;
 
         // add to bit_counts
         for (i, digit) in digits.into_iter().enumerate() {
@@ -66,16 +56,16 @@ impl<const N: usize> Parser<N> {
         Self::bits_to_number(&bits)
     }
 
-    pub fn oxygen_generator_rating(&self) -> Result<u32, anyhow::Error> {
+    pub fn oxygen_generator_rating(&self) -> Result<u32, DiagnosticError> {
         let records: Vec<&[u32; N]> = self.records.iter().collect();
-        let record = Self::filter_list(records, 0, Ordering::Greater)?;
+        let record = Self::filter_list(records, 0, BitCriteria::MostCommon)?;
 
         Ok(Self::bits_to_number(record))
     }
 
-    pub fn co2_scrubber_rating(&self) -> Result<u32, anyhow::Error> {
+    pub fn co2_scrubber_rating(&self) -> Result<u32, DiagnosticError> {
         let records: Vec<&[u32; N]> = self.records.iter().collect();
-        let record = Self::filter_list(records, 0, Ordering::Less)?;
+        let record = Self::filter_list(records, 0, BitCriteria::LeastCommon)?;
 
         Ok(Self::bits_to_number(record))
     }
@@ -83,31 +73,38 @@ impl<const N: usize> Parser<N> {
     fn filter_list(
         list: Vec<&[u32; N]>,
         index: usize,
-        ordering: Ordering,
-    ) -> Result<&[u32; N], anyhow::Error> {
+        criteria: BitCriteria,
+    ) -> Result<&[u32; N], DiagnosticError> {
+        if list.is_empty() {
+            return Err(DiagnosticError::NoRecords);
+        }
+
+        if index == N {
+            return Err(DiagnosticError::AmbiguousRecords { count: list.len() });
+        }
+
         let bit_1_count = list.iter().filter(|item| item[index] == 1).count();
         let bit_0_count = list.iter().filter(|item| item[index] == 0).count();
 
-        let bit = match ordering {
-            Ordering::Greater => match bit_1_count.cmp(&bit_0_count) {
+        let bit = match criteria {
+            BitCriteria::MostCommon => match bit_1_count.cmp(&bit_0_count) {
                 Ordering::Greater => 1,
                 Ordering::Less => 0,
                 Ordering::Equal => 1,
             },
-            Ordering::Less => match bit_1_count.cmp(&bit_0_count) {
+            BitCriteria::LeastCommon => match bit_1_count.cmp(&bit_0_count) {
                 Ordering::Greater => 0,
                 Ordering::Less => 1,
                 Ordering::Equal => 0,
             },
-            Ordering::Equal => return Err(anyhow::anyhow!("invalid ordering parameter")),
         };
 
         let new_list: Vec<&[u32; N]> = list.into_iter().filter(|item| item[index] == bit).collect();
 
         match new_list.len().cmp(&1) {
             Ordering::Equal => Ok(new_list[0]),
-            Ordering::Greater => Self::filter_list(new_list, index + 1, ordering),
-            Ordering::Less => Err(anyhow::anyhow!("failed to filter down to a single record")),
+            Ordering::Greater => Self::filter_list(new_list, index + 1, criteria),
+            Ordering::Less => Err(DiagnosticError::NoMatchingRecord { index }),
         }
     }
 
@@ -122,6 +119,12 @@ impl<const N: usize> Parser<N> {
     }
 }
 
+impl<const N: usize> Default for Parser<N> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -174,4 +177,29 @@ mod tests {
 
         assert_eq!(parser.co2_scrubber_rating().unwrap(), 10);
     }
+
+    #[test]
+    fn test_rating_errors() {
+        let parser = Parser::<5>::new();
+        assert_eq!(
+            parser.oxygen_generator_rating(),
+            Err(DiagnosticError::NoRecords)
+        );
+
+        let mut parser = Parser::<5>::new();
+        parser.parse_line("00100").unwrap();
+        parser.parse_line("00100").unwrap();
+        assert_eq!(
+            parser.oxygen_generator_rating(),
+            Err(DiagnosticError::AmbiguousRecords { count: 2 })
+        );
+
+        let mut parser = Parser::<5>::new();
+        parser.parse_line("00100").unwrap();
+        parser.parse_line("00101").unwrap();
+        assert_eq!(
+            parser.co2_scrubber_rating(),
+            Err(DiagnosticError::NoMatchingRecord { index: 0 })
+        );
+    }
 }
@@ -0,0 +1,42 @@
+use std::error::Error;
+
+use aoc_common::{
+    parse::{AtLine, ParseError},
+    Solution,
+};
+
+use crate::parser::Parser;
+
+// https://adventofcode.com/2021/day/3
+pub struct Day3;
+
+impl Day3 {
+    fn parse(input: &str) -> Result<Parser<12>, ParseError> {
+        let mut parser = Parser::<12>::new();
+
+        for (index, line) in input.lines().enumerate() {
+            parser.parse_line(line).at_line(index + 1)?;
+        }
+
+        Ok(parser)
+    }
+}
+
+impl Solution for Day3 {
+    const DAY: u8 = 3;
+
+    type Part1 = u32;
+    type Part2 = u32;
+
+    fn part_1(input: &str) -> Result<Self::Part1, Box<dyn Error>> {
+        let parser = Self::parse(input)?;
+
+        Ok(parser.gamma_rate() * parser.epsilon_rate())
+    }
+
+    fn part_2(input: &str) -> Result<Self::Part2, Box<dyn Error>> {
+        let parser = Self::parse(input)?;
+
+        Ok(parser.oxygen_generator_rating()? * parser.co2_scrubber_rating()?)
+    }
+}
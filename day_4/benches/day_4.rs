@@ -0,0 +1,46 @@
+use criterion::{criterion_group, criterion_main, BatchSize, Criterion};
+use day_4::bingo_subsystem::BingoSubsystem;
+
+const EXAMPLE: &str = r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
+
+22 13 17 11  0
+8  2 23  4 24
+21  9 14 16  7
+6 10  3 18  5
+1 12 20 15 19
+
+3 15  0  2 22
+9 18 13 17  5
+19  8  7 25 23
+20 11 10 24  4
+14 21 16 12  6
+
+14 21 17 24  4
+10 16 15  9 19
+18  8 23 26 20
+22 11 13  6  5
+2  0 12  3  7
+"#;
+const INPUT: &str = include_str!("../input.txt");
+
+fn draw_to_lose(c: &mut Criterion) {
+    let mut group = c.benchmark_group("BingoSubsystem::draw_to_lose");
+
+    for (name, input) in [("example", EXAMPLE), ("input", INPUT)] {
+        let subsystem = BingoSubsystem::parse(input, 5).unwrap();
+
+        // draw_to_lose consumes the subsystem, so every iteration gets a fresh clone
+        group.bench_function(name, |b| {
+            b.iter_batched(
+                || subsystem.clone(),
+                |subsystem| subsystem.draw_to_lose().unwrap(),
+                BatchSize::SmallInput,
+            )
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, draw_to_lose);
+criterion_main!(benches);
@@ -1,54 +1,60 @@
 use std::collections::VecDeque;
 
-use anyhow::Context;
+use aoc_common::parse::{self, AtLine, ParseError, ParseErrorKind};
 
-use crate::board::Board;
+use crate::{board::Board, error::BingoError};
 
 #[derive(Debug, Clone)]
 pub struct BingoSubsystem {
-    board_size: usize,
     future_draws: VecDeque<usize>,
     boards: Vec<Board>,
 }
 
 impl BingoSubsystem {
-    pub fn new(board_size: usize) -> Self {
-        Self {
-            board_size,
+    /// Reads the draws on the first line, then one board per block of rows.
+    pub fn parse(input: &str, board_size: usize) -> Result<Self, BingoError> {
+        let mut subsystem = Self {
             future_draws: VecDeque::new(),
             boards: Vec::new(),
-        }
-    }
+        };
+        let mut blocks = parse::blocks(input);
 
-    pub fn parse_line(&mut self, input: &str) -> Result<(), anyhow::Error> {
-        if self.future_draws.is_empty() {
-            let values = input
-                .split(',')
-                .map(|value| value.parse::<usize>())
-                .collect::<Result<VecDeque<usize>, _>>()?;
+        if let Some((line, draws)) = blocks.next() {
+            let mut lines = draws.lines();
 
-            self.future_draws.extend(values);
-        } else {
-            let input = input.trim();
+            let draws = lines.next().unwrap_or_default();
+            let values = parse::comma_separated::<usize>(draws).at_line(line)?;
+            subsystem.future_draws.extend(values);
 
-            if !input.is_empty() {
-                if self.boards.is_empty() || self.boards[self.boards.len() - 1].is_full() {
-                    self.boards.push(Board::new(self.board_size));
-                }
+            if let Some(row) = lines.next() {
+                return Err(unexpected(row).at_line(line + 1).into());
+            }
+        }
 
-                let board_index = self.boards.len() - 1;
-                let board = &mut self.boards[board_index];
+        for (line, block) in blocks {
+            let rows = parse::whitespace_grid::<usize>(block, board_size).map_err(|error| {
+                let line = line + error.line - 1;
+                error.at_line(line)
+            })?;
 
-                let values = input
-                    .split_whitespace()
-                    .map(|value| value.trim().parse::<usize>())
-                    .collect::<Result<Vec<usize>, _>>()?;
+            if rows.len() < board_size {
+                let index = subsystem.boards.len();
+                return Err(BingoError::IncompleteBoard { index });
+            }
+
+            if let Some(row) = block.lines().nth(board_size) {
+                return Err(unexpected(row).at_line(line + board_size).into());
+            }
 
-                board.add_row(&values);
+            let mut board = Board::new(board_size);
+            for row in &rows {
+                board.add_row(row);
             }
+
+            subsystem.boards.push(board);
         }
 
-        Ok(())
+        Ok(subsystem)
     }
 
     pub fn all_boards_are_full(&self) -> bool {
@@ -61,11 +67,15 @@ impl BingoSubsystem {
         true
     }
 
-    pub fn draw_to_win(mut self) -> Result<usize, anyhow::Error> {
+    pub fn draw_to_win(mut self) -> Result<usize, BingoError> {
+        if self.boards.is_empty() {
+            return Err(BingoError::NoBoards);
+        }
+
         let value = self
             .future_draws
             .pop_front()
-            .context("future draws is empty")?;
+            .ok_or(BingoError::DrawsExhausted)?;
 
         for board in &mut self.boards {
             let score = board.mark(value);
@@ -78,15 +88,15 @@ impl BingoSubsystem {
         self.draw_to_win()
     }
 
-    pub fn draw_to_lose(mut self) -> Result<usize, anyhow::Error> {
+    pub fn draw_to_lose(mut self) -> Result<usize, BingoError> {
         loop {
             let value = self
                 .future_draws
                 .pop_front()
-                .context("future draws is empty")?;
+                .ok_or(BingoError::DrawsExhausted)?;
 
             match self.boards.len() {
-                0 => return Err(anyhow::anyhow!("no boards")),
+                0 => return Err(BingoError::NoBoards),
                 1 => {
                     let board = &mut self.boards[0];
 
@@ -107,10 +117,18 @@ impl BingoSubsystem {
     }
 }
 
+/// A row past the end of the draws or of a board.
+fn unexpected(row: &str) -> ParseError {
+    let value = row.trim();
+    let kind = ParseErrorKind::UnexpectedField {
+        value: value.to_string(),
+    };
+
+    ParseError::new(row, value, kind)
+}
+
 #[cfg(test)]
 mod tests {
-    use std::io::{BufRead, BufReader};
-
     use super::*;
 
     const INPUT: &str = r#"7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1
@@ -134,21 +152,16 @@ mod tests {
 2  0 12  3  7
 "#;
 
-    fn build_subsystem() -> BingoSubsystem {
-        let reader = BufReader::new(INPUT.as_bytes());
-        let mut subsystem = BingoSubsystem::new(5);
-
-        for line in reader.lines() {
-            let line = line.unwrap();
-            subsystem.parse_line(&line).unwrap();
+    fn parse_error(input: &str) -> ParseError {
+        match BingoSubsystem::parse(input, 5) {
+            Err(BingoError::Parse(error)) => error,
+            result => panic!("expected a parse error, got {result:?}"),
         }
-
-        subsystem
     }
 
     #[test]
     fn test_parse() {
-        let subsystem = build_subsystem();
+        let subsystem = BingoSubsystem::parse(INPUT, 5).unwrap();
 
         assert_eq!(subsystem.future_draws.len(), 27);
         assert_eq!(subsystem.future_draws[0], 7);
@@ -156,11 +169,65 @@ mod tests {
 
         assert_eq!(subsystem.boards.len(), 3);
         assert!(subsystem.all_boards_are_full());
+
+        let crlf = INPUT.replace('\n', "\r\n");
+        assert_eq!(BingoSubsystem::parse(&crlf, 5).unwrap().boards.len(), 3);
+    }
+
+    #[test]
+    fn test_errors() {
+        let subsystem = BingoSubsystem::parse("7,4,9\n", 5).unwrap();
+        assert_eq!(subsystem.draw_to_win().unwrap_err(), BingoError::NoBoards);
+
+        assert_eq!(
+            BingoSubsystem::parse("7,4,9\n\n22 13 17 11  0\n", 5).unwrap_err(),
+            BingoError::IncompleteBoard { index: 0 }
+        );
+
+        let input = INPUT.replacen(
+            "7,4,9,5,11,17,23,2,0,14,21,24,10,16,13,6,15,25,12,22,18,20,8,19,3,26,1",
+            "7,4,9",
+            1,
+        );
+        let subsystem = BingoSubsystem::parse(&input, 5).unwrap();
+        assert_eq!(subsystem.draw_to_lose(), Err(BingoError::DrawsExhausted));
+    }
+
+    #[test]
+    fn test_error_column_in_indented_row() {
+        let error = parse_error("7,4,9\n\n22 13 17 11  0\n 8  2 2x  4 24\n");
+
+        assert_eq!((error.line, error.column), (4, 7));
+        assert_eq!(error.snippet, " 8  2 2x  4 24");
+    }
+
+    #[test]
+    fn test_row_length() {
+        for row in [" 8  2 23  4", " 8  2 23  4 24  9"] {
+            let error = parse_error(&format!("7,4,9\n\n{row}\n"));
+
+            assert_eq!((error.line, error.column), (3, 2));
+            assert!(matches!(
+                error.kind,
+                ParseErrorKind::InvalidLength { expected: 5, .. }
+            ));
+        }
+    }
+
+    #[test]
+    fn test_extra_rows() {
+        let board = INPUT.lines().skip(2).take(5).collect::<Vec<_>>().join("\n");
+
+        let error = parse_error(&format!("7,4,9\n\n{board}\n 3 15  0  2 22\n"));
+        assert_eq!((error.line, error.column), (8, 2));
+
+        let error = parse_error(&format!("7,4,9\n4,5\n\n{board}\n"));
+        assert_eq!((error.line, error.column), (2, 1));
     }
 
     #[test]
     fn test_draw_to_win() {
-        let subsystem = build_subsystem();
+        let subsystem = BingoSubsystem::parse(INPUT, 5).unwrap();
 
         let score = subsystem.draw_to_win().unwrap();
         assert_eq!(score, 4512);
@@ -168,7 +235,7 @@ mod tests {
 
     #[test]
     fn test_draw_to_lose() {
-        let subsystem = build_subsystem();
+        let subsystem = BingoSubsystem::parse(INPUT, 5).unwrap();
 
         let score = subsystem.draw_to_lose().unwrap();
         assert_eq!(score, 1924);
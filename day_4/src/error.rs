@@ -0,0 +1,14 @@
+use aoc_common::parse::ParseError;
+use thiserror::Error;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum BingoError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error("board {index} is incomplete")]
+    IncompleteBoard { index: usize },
+    #[error("no boards")]
+    NoBoards,
+    #[error("all numbers were drawn without a bingo")]
+    DrawsExhausted,
+}
@@ -0,0 +1,7 @@
+pub mod bingo_subsystem;
+pub mod board;
+pub mod cell;
+pub mod error;
+mod solution;
+
+pub use solution::Day4;
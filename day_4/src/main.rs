@@ -1,34 +1,11 @@
-use std::{
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::path::Path;
 
-mod bingo_subsystem;
-mod board;
-mod cell;
-
-use bingo_subsystem::BingoSubsystem;
+use aoc_common::{Args, MainError};
+use day_4::Day4;
 
 // https://adventofcode.com/2021/day/4
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = File::open("day_4/input.txt")?;
-    let buffer = BufReader::new(file);
-
-    let mut subsystem = BingoSubsystem::new(5);
-
-    for line in buffer.lines() {
-        let line = line?;
-        subsystem.parse_line(&line)?;
-    }
-
-    assert!(subsystem.all_boards_are_full());
-
-    let score = subsystem.clone().draw_to_win()?;
-    println!("part 1: {score}");
-
-    let score = subsystem.draw_to_lose()?;
-    println!("part 2: {score}");
+fn main() -> Result<(), MainError> {
+    let args = Args::from_env();
 
-    Ok(())
+    aoc_common::run::<Day4>(&args, Path::new(env!("CARGO_MANIFEST_DIR")))
 }
@@ -0,0 +1,45 @@
+use std::hint::black_box;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_5::{line::Line, parser::Parser};
+
+const EXAMPLE: &str = r#"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+"#;
+const INPUT: &str = include_str!("../input.txt");
+
+fn parse(c: &mut Criterion) {
+    let mut group = c.benchmark_group("Parser::parse");
+
+    for (name, input) in [("example", EXAMPLE), ("input", INPUT)] {
+        let lines = input
+            .lines()
+            .map(|line| line.parse::<Line>().unwrap())
+            .collect::<Vec<_>>();
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut parser = Parser::new();
+
+                for line in black_box(&lines) {
+                    parser.parse(line).unwrap();
+                }
+
+                parser.dangerous_areas()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, parse);
+criterion_main!(benches);
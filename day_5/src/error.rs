@@ -0,0 +1,12 @@
+use aoc_common::parse::ParseError;
+use thiserror::Error;
+
+use crate::line::Line;
+
+#[derive(Debug, Error, PartialEq, Eq)]
+pub enum VentError {
+    #[error(transparent)]
+    Parse(#[from] ParseError),
+    #[error("line {0} is neither horizontal, vertical nor diagonal at 45 degrees")]
+    UnsupportedSlope(Line),
+}
@@ -0,0 +1,7 @@
+pub mod error;
+pub mod line;
+pub mod parser;
+pub mod point;
+mod solution;
+
+pub use solution::Day5;
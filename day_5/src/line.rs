@@ -1,10 +1,10 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-use anyhow::Context;
+use aoc_common::parse::{self, ParseError};
 
 use crate::point::Point;
 
-#[derive(Debug, PartialEq, Eq)]
+#[derive(Debug, Clone, PartialEq, Eq)]
 pub struct Line {
     pub start: Point,
     pub end: Point,
@@ -18,18 +18,27 @@ impl Line {
     pub fn is_diagonal(&self) -> bool {
         self.start.x != self.end.x && self.start.y != self.end.y
     }
+
+    /// Horizontal, vertical or diagonal at exactly 45 degrees.
+    pub fn is_supported(&self) -> bool {
+        !self.is_diagonal()
+            || self.start.x.abs_diff(self.end.x) == self.start.y.abs_diff(self.end.y)
+    }
 }
 
 impl FromStr for Line {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split(" -> ");
+        let ((x1, y1), (x2, y2)) = parse::segment(s)?;
 
-        let start = parts.next().context("failed to find Line Start")?.parse()?;
-        let end = parts.next().context("failed to find Line End")?.parse()?;
+        Ok(Line::new(Point::new(x1, y1), Point::new(x2, y2)))
+    }
+}
 
-        Ok(Line::new(start, end))
+impl fmt::Display for Line {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{} -> {}", self.start, self.end)
     }
 }
 
@@ -45,13 +54,14 @@ mod tests {
 
     #[test]
     fn test_is_diagonal() {
-        assert_eq!(
-            Line::new(Point::new(0, 9), Point::new(5, 9)).is_diagonal(),
-            false
-        );
-        assert_eq!(
-            Line::new(Point::new(8, 0), Point::new(0, 8)).is_diagonal(),
-            true
-        );
+        assert!(!Line::new(Point::new(0, 9), Point::new(5, 9)).is_diagonal());
+        assert!(Line::new(Point::new(8, 0), Point::new(0, 8)).is_diagonal());
+    }
+
+    #[test]
+    fn test_is_supported() {
+        assert!(Line::new(Point::new(0, 9), Point::new(5, 9)).is_supported());
+        assert!(Line::new(Point::new(8, 0), Point::new(0, 8)).is_supported());
+        assert!(!Line::new(Point::new(0, 0), Point::new(2, 1)).is_supported());
     }
 }
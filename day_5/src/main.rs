@@ -1,41 +1,11 @@
-use std::{
-    error::Error,
-    fs::File,
-    io::{BufRead, BufReader},
-};
+use std::path::Path;
 
-mod line;
-mod parser;
-mod point;
-
-use line::Line;
-use parser::Parser;
+use aoc_common::{Args, MainError};
+use day_5::Day5;
 
 // https://adventofcode.com/2021/day/5
-fn main() -> Result<(), Box<dyn Error>> {
-    let file = File::open("day_5/input.txt")?;
-    let buffer = BufReader::new(file);
-
-    let mut parser = Parser::new();
-    let mut diagonal_lines = Vec::new();
-
-    for line in buffer.lines() {
-        if let Ok(line) = line.unwrap().parse::<Line>() {
-            if !line.is_diagonal() {
-                parser.parse(&line);
-            } else {
-                diagonal_lines.push(line);
-            }
-        }
-    }
-
-    println!("part 1: {}", parser.dangerous_areas());
-
-    for line in diagonal_lines {
-        parser.parse(&line);
-    }
-
-    println!("part 2: {}", parser.dangerous_areas());
+fn main() -> Result<(), MainError> {
+    let args = Args::from_env();
 
-    Ok(())
+    aoc_common::run::<Day5>(&args, Path::new(env!("CARGO_MANIFEST_DIR")))
 }
@@ -1,6 +1,6 @@
 use std::{cmp, collections::HashMap};
 
-use crate::{line::Line, point::Point};
+use crate::{error::VentError, line::Line, point::Point};
 
 pub struct Parser {
     vents: HashMap<Point, usize>,
@@ -13,7 +13,11 @@ impl Parser {
         }
     }
 
-    pub fn parse(&mut self, line: &Line) {
+    pub fn parse(&mut self, line: &Line) -> Result<(), VentError> {
+        if !line.is_supported() {
+            return Err(VentError::UnsupportedSlope(line.clone()));
+        }
+
         let mut x = line.start.x;
         let mut y = line.start.y;
 
@@ -23,7 +27,7 @@ impl Parser {
             *count += 1;
 
             if x == line.end.x && y == line.end.y {
-                break;
+                return Ok(());
             }
 
             match x.cmp(&line.end.x) {
@@ -45,9 +49,18 @@ impl Parser {
     }
 }
 
+impl Default for Parser {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 #[cfg(test)]
 mod tests {
-    use std::io::{BufRead, BufReader};
+    use std::{
+        io::{BufRead, BufReader},
+        str::FromStr,
+    };
 
     use super::*;
 
@@ -71,7 +84,7 @@ mod tests {
         for line in reader.lines() {
             if let Ok(line) = line.unwrap().parse::<Line>() {
                 if !line.is_diagonal() {
-                    parser.parse(&line);
+                    parser.parse(&line).unwrap();
                 }
             }
         }
@@ -86,10 +99,18 @@ mod tests {
 
         for line in reader.lines() {
             if let Ok(line) = line.unwrap().parse::<Line>() {
-                parser.parse(&line);
+                parser.parse(&line).unwrap();
             }
         }
 
         assert_eq!(parser.dangerous_areas(), 12);
     }
+
+    #[test]
+    fn test_parse_unsupported_slope() {
+        let mut parser = Parser::new();
+        let line = Line::from_str("0,0 -> 2,1").unwrap();
+
+        assert_eq!(parser.parse(&line), Err(VentError::UnsupportedSlope(line)));
+    }
 }
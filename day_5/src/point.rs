@@ -1,8 +1,8 @@
-use std::str::FromStr;
+use std::{fmt, str::FromStr};
 
-use anyhow::Context;
+use aoc_common::parse::{self, ParseError};
 
-#[derive(Debug, PartialEq, Eq, Hash)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
 pub struct Point {
     pub x: usize,
     pub y: usize,
@@ -15,17 +15,21 @@ impl Point {
 }
 
 impl FromStr for Point {
-    type Err = anyhow::Error;
+    type Err = ParseError;
 
     fn from_str(s: &str) -> Result<Self, Self::Err> {
-        let mut parts = s.split(',');
-        let x = parts.next().context("failed to find Point X")?.parse()?;
-        let y = parts.next().context("failed to find Point Y")?.parse()?;
+        let (x, y) = parse::pair(s, ',')?;
 
         Ok(Point::new(x, y))
     }
 }
 
+impl fmt::Display for Point {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{},{}", self.x, self.y)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,86 @@
+use std::error::Error;
+
+use aoc_common::{
+    parse::{AtLine, ParseError},
+    Solution,
+};
+
+use crate::{line::Line, parser::Parser};
+
+// https://adventofcode.com/2021/day/5
+pub struct Day5;
+
+impl Day5 {
+    fn lines(input: &str) -> Result<Vec<Line>, ParseError> {
+        input
+            .lines()
+            .enumerate()
+            .map(|(index, line)| line.parse::<Line>().at_line(index + 1))
+            .collect()
+    }
+}
+
+impl Solution for Day5 {
+    const DAY: u8 = 5;
+
+    type Part1 = usize;
+    type Part2 = usize;
+
+    fn part_1(input: &str) -> Result<Self::Part1, Box<dyn Error>> {
+        let mut parser = Parser::new();
+
+        for line in Self::lines(input)?
+            .iter()
+            .filter(|line| !line.is_diagonal())
+        {
+            parser.parse(line)?;
+        }
+
+        Ok(parser.dangerous_areas())
+    }
+
+    fn part_2(input: &str) -> Result<Self::Part2, Box<dyn Error>> {
+        let mut parser = Parser::new();
+
+        for line in Self::lines(input)? {
+            parser.parse(&line)?;
+        }
+
+        Ok(parser.dangerous_areas())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = r#"0,9 -> 5,9
+8,0 -> 0,8
+9,4 -> 3,4
+2,2 -> 2,1
+7,0 -> 7,4
+6,4 -> 2,0
+0,9 -> 2,9
+3,4 -> 1,4
+0,0 -> 8,8
+5,5 -> 8,2
+"#;
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day5::part_1(INPUT).unwrap(), 5);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day5::part_2(INPUT).unwrap(), 12);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let input = "0,9 -> 5,9\n8,0 -> 0,x\n";
+
+        assert!(Day5::part_1(input).is_err());
+        assert!(Day5::part_2(input).is_err());
+    }
+}
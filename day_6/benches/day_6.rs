@@ -0,0 +1,36 @@
+use std::{collections::HashMap, hint::black_box};
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use day_6::lanternfish::tick;
+
+const EXAMPLE: &str = "3,4,3,1,2\n";
+const INPUT: &str = include_str!("../input.txt");
+const DAYS: i64 = 256;
+
+fn ticks(c: &mut Criterion) {
+    let mut group = c.benchmark_group("tick");
+
+    for (name, input) in [("example", EXAMPLE), ("input", INPUT)] {
+        let hatch_times = input
+            .trim()
+            .split(',')
+            .map(|value| value.parse::<i64>().unwrap())
+            .collect::<Vec<_>>();
+
+        group.bench_function(name, |b| {
+            b.iter(|| {
+                let mut cache = HashMap::new();
+
+                black_box(&hatch_times)
+                    .iter()
+                    .map(|hatch_time| tick(&mut cache, DAYS - hatch_time))
+                    .sum::<i64>()
+            })
+        });
+    }
+
+    group.finish();
+}
+
+criterion_group!(benches, ticks);
+criterion_main!(benches);
@@ -0,0 +1,4 @@
+pub mod lanternfish;
+mod solution;
+
+pub use solution::Day6;
@@ -0,0 +1,67 @@
+use std::{collections::HashMap, error::Error};
+
+use aoc_common::{
+    parse::{self, ParseError},
+    Solution,
+};
+
+use crate::lanternfish::tick;
+
+const PART_1_DAYS: i64 = 80;
+const PART_2_DAYS: i64 = 256;
+
+// https://adventofcode.com/2021/day/6
+pub struct Day6;
+
+impl Day6 {
+    fn count(input: &str, days: i64) -> Result<i64, ParseError> {
+        let mut cache = HashMap::new();
+
+        let mut count = 0;
+        for hatch_time in parse::comma_separated::<i64>(input)? {
+            count += tick(&mut cache, days - hatch_time);
+        }
+
+        Ok(count)
+    }
+}
+
+impl Solution for Day6 {
+    const DAY: u8 = 6;
+
+    type Part1 = i64;
+    type Part2 = i64;
+
+    fn part_1(input: &str) -> Result<Self::Part1, Box<dyn Error>> {
+        Ok(Self::count(input, PART_1_DAYS)?)
+    }
+
+    fn part_2(input: &str) -> Result<Self::Part2, Box<dyn Error>> {
+        Ok(Self::count(input, PART_2_DAYS)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const INPUT: &str = "3,4,3,1,2\n";
+
+    #[test]
+    fn test_part_1() {
+        assert_eq!(Day6::part_1(INPUT).unwrap(), 5934);
+    }
+
+    #[test]
+    fn test_part_2() {
+        assert_eq!(Day6::part_2(INPUT).unwrap(), 26984457539);
+    }
+
+    #[test]
+    fn test_invalid_input() {
+        let input = "3,4,x,1,2\n";
+
+        assert!(Day6::part_1(input).is_err());
+        assert!(Day6::part_2(input).is_err());
+    }
+}
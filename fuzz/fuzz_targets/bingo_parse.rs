@@ -0,0 +1,8 @@
+#![no_main]
+
+use day_4::bingo_subsystem::BingoSubsystem;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = BingoSubsystem::parse(data, 5);
+});
@@ -0,0 +1,10 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use day_2::command::Command;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = Command::from_str(data);
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use day_2::binary::{decode_course, encode_course};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    if let Ok(commands) = decode_course(data) {
+        // overlong varints decode too, so only the commands have to survive a round trip
+        assert_eq!(decode_course(&encode_course(&commands)), Ok(commands));
+    }
+});
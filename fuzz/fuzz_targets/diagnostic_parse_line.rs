@@ -0,0 +1,17 @@
+#![no_main]
+
+use day_3::parser::Parser;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let mut parser_5 = Parser::<5>::new();
+    let mut parser_12 = Parser::<12>::new();
+
+    for line in data.lines() {
+        let _ = parser_5.parse_line(line);
+        let _ = parser_12.parse_line(line);
+    }
+
+    let _ = parser_5.oxygen_generator_rating();
+    let _ = parser_5.co2_scrubber_rating();
+});
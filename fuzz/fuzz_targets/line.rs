@@ -0,0 +1,10 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use day_5::line::Line;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let _ = Line::from_str(data);
+});
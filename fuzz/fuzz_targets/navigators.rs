@@ -0,0 +1,33 @@
+#![no_main]
+
+use std::str::FromStr;
+
+use day_2::{
+    command::Command, navigator::navigate_all, navigator_3d::Navigator3d,
+    navigator_advanced::NavigatorAdvanced, navigator_simple::NavigatorSimple,
+};
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    let commands: Vec<_> = data
+        .lines()
+        .filter_map(|line| Command::from_str(line).ok())
+        .collect();
+
+    let mut navigator_simple = NavigatorSimple::new();
+    let mut navigator_advanced = NavigatorAdvanced::new();
+    let mut navigator_3d = Navigator3d::new();
+
+    let positions = navigate_all(
+        &commands,
+        &mut [
+            &mut navigator_simple,
+            &mut navigator_advanced,
+            &mut navigator_3d,
+        ],
+    );
+
+    for position in positions.into_iter().flatten() {
+        position.product();
+    }
+});
@@ -0,0 +1,11 @@
+#![no_main]
+
+use day_2::script::Script;
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &str| {
+    if let Ok(script) = Script::compile(data) {
+        // loops can unroll to far more commands than a run has time for
+        script.commands().take(10_000).for_each(drop);
+    }
+});